@@ -223,6 +223,17 @@ builtin_macros_requires_cfg_pattern =
 
 builtin_macros_should_panic = functions using `#[should_panic]` must return `()`
 
+builtin_macros_smart_pointer_multiple_pointees = `SmartPointer` can only be derived with a single `#[pointee]` type parameter
+    .label = `#[pointee]` used here
+    .label_again = `#[pointee]` used again here
+
+builtin_macros_smart_pointer_no_pointee = `SmartPointer` can only be derived if one of the type parameters is marked as `#[pointee]`
+    .help = mark the type parameter of the pointed-to type with `#[pointee]`
+
+builtin_macros_smart_pointer_not_struct = `SmartPointer` can only be derived on `struct`s
+
+builtin_macros_smart_pointer_pointee_not_type = `#[pointee]` can only be used on type parameters
+
 builtin_macros_test_arg_non_lifetime = functions used as tests can not have any non-lifetime generic parameters
 
 builtin_macros_test_args = functions used as tests can not have any arguments
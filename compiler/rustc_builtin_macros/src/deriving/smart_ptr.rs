@@ -0,0 +1,131 @@
+use crate::errors;
+
+use rustc_ast::{self as ast, GenericArg, GenericBound, GenericParamKind, ItemKind, MetaItem};
+use rustc_expand::base::{Annotatable, ExtCtxt};
+use rustc_span::symbol::{sym, Ident, Symbol};
+use rustc_span::Span;
+use thin_vec::{thin_vec, ThinVec};
+
+/// Derives `CoerceUnsized` and `DispatchFromDyn` for a struct with one type parameter marked as
+/// `#[pointee]`. For `struct MyBox<'a, #[pointee] T: ?Sized, A>`, this produces
+///
+/// ```ignore (illustrative)
+/// impl<'a, T: ?Sized + Unsize<__S>, A, __S: ?Sized> CoerceUnsized<MyBox<'a, __S, A>>
+///     for MyBox<'a, T, A> {}
+/// ```
+///
+/// and the same impl of `DispatchFromDyn`. Whether the struct is actually a valid smart pointer
+/// is checked for these impls like for any other impl of those traits.
+pub fn expand_deriving_smart_ptr(
+    cx: &mut ExtCtxt<'_>,
+    span: Span,
+    _mitem: &MetaItem,
+    item: &Annotatable,
+    push: &mut dyn FnMut(Annotatable),
+    _is_const: bool,
+) {
+    let (name_ident, generics) = if let Annotatable::Item(aitem) = item
+        && let ItemKind::Struct(_, g) = &aitem.kind
+    {
+        (aitem.ident, g)
+    } else {
+        cx.dcx().emit_err(errors::SmartPointerNotStruct { span });
+        return;
+    };
+
+    // Exactly one of the type parameters has to be marked as `#[pointee]`.
+    let mut pointee_params = generics
+        .params
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.attrs.iter().any(|attr| attr.has_name(sym::pointee)));
+    let (pointee_idx, _) = match (pointee_params.next(), pointee_params.next()) {
+        (Some(pointee), None) => pointee,
+        (None, _) => {
+            cx.dcx().emit_err(errors::SmartPointerNoPointee { span });
+            return;
+        }
+        (Some((_, first)), Some((_, second))) => {
+            cx.dcx().emit_err(errors::SmartPointerMultiplePointees {
+                span,
+                first: first.span(),
+                second: second.span(),
+            });
+            return;
+        }
+    };
+    if !matches!(generics.params[pointee_idx].kind, GenericParamKind::Type { .. }) {
+        cx.dcx().emit_err(errors::SmartPointerPointeeNotType {
+            span: generics.params[pointee_idx].span(),
+        });
+        return;
+    }
+
+    // Convert the generic parameters of the struct into generic arguments.
+    let self_params: Vec<_> = generics
+        .params
+        .iter()
+        .map(|p| match p.kind {
+            GenericParamKind::Lifetime => GenericArg::Lifetime(cx.lifetime(p.span(), p.ident)),
+            GenericParamKind::Type { .. } => GenericArg::Type(cx.ty_ident(p.span(), p.ident)),
+            GenericParamKind::Const { .. } => GenericArg::Const(cx.const_ident(p.span(), p.ident)),
+        })
+        .collect();
+
+    // The type the struct is coerced to has `__S` in place of the pointee.
+    let unsized_ident = Ident::new(sym::__S, span);
+    let unsized_ty = cx.ty_ident(span, unsized_ident);
+    let self_ty = cx.ty_path(cx.path_all(span, false, vec![name_ident], self_params.clone()));
+    let mut target_params = self_params;
+    target_params[pointee_idx] = GenericArg::Type(unsized_ty.clone());
+    let target_ty = cx.ty_path(cx.path_all(span, false, vec![name_ident], target_params));
+
+    // The impls need the generics of the struct, with an `Unsize<__S>` bound on the pointee and
+    // with `__S: ?Sized` added.
+    let mut impl_generics = generics.clone();
+    let pointee = &mut impl_generics.params[pointee_idx];
+    pointee.attrs.retain(|attr| !attr.has_name(sym::pointee));
+    let unsize = cx.path_all(
+        span,
+        false,
+        cx.std_path(&[sym::marker, sym::Unsize]),
+        vec![GenericArg::Type(unsized_ty)],
+    );
+    pointee.bounds.push(cx.trait_bound(unsize, false));
+    let sized = cx.path(span, cx.std_path(&[sym::marker, sym::Sized]));
+    let maybe_sized = GenericBound::Trait(
+        cx.poly_trait_ref(span, sized),
+        ast::TraitBoundModifiers {
+            constness: ast::BoundConstness::Never,
+            polarity: ast::BoundPolarity::Maybe(span),
+        },
+    );
+    impl_generics.params.push(cx.typaram(span, unsized_ident, vec![maybe_sized], None));
+
+    let mut add_impl = |trait_name: Symbol| {
+        let trait_path = cx.path_all(
+            span,
+            false,
+            cx.std_path(&[sym::ops, trait_name]),
+            vec![GenericArg::Type(target_ty.clone())],
+        );
+        let item = cx.item(
+            span,
+            Ident::empty(),
+            thin_vec![cx.attr_word(sym::automatically_derived, span)],
+            ItemKind::Impl(Box::new(ast::Impl {
+                unsafety: ast::Unsafe::No,
+                polarity: ast::ImplPolarity::Positive,
+                defaultness: ast::Defaultness::Final,
+                constness: ast::Const::No,
+                generics: impl_generics.clone(),
+                of_trait: Some(cx.trait_ref(trait_path)),
+                self_ty: self_ty.clone(),
+                items: ThinVec::new(),
+            })),
+        );
+        push(Annotatable::Item(item));
+    };
+    add_impl(sym::DispatchFromDyn);
+    add_impl(sym::CoerceUnsized);
+}
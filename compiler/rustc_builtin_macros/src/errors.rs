@@ -373,6 +373,39 @@ pub(crate) struct NonUnitDefault {
     pub(crate) span: Span,
 }
 
+#[derive(Diagnostic)]
+#[diag(builtin_macros_smart_pointer_not_struct)]
+pub(crate) struct SmartPointerNotStruct {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_smart_pointer_no_pointee)]
+#[help]
+pub(crate) struct SmartPointerNoPointee {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_smart_pointer_multiple_pointees)]
+pub(crate) struct SmartPointerMultiplePointees {
+    #[primary_span]
+    pub(crate) span: Span,
+    #[label]
+    pub(crate) first: Span,
+    #[label(builtin_macros_label_again)]
+    pub(crate) second: Span,
+}
+
+#[derive(Diagnostic)]
+#[diag(builtin_macros_smart_pointer_pointee_not_type)]
+pub(crate) struct SmartPointerPointeeNotType {
+    #[primary_span]
+    pub(crate) span: Span,
+}
+
 #[derive(Diagnostic)]
 #[diag(builtin_macros_non_exhaustive_default)]
 #[help]
@@ -2,7 +2,7 @@
 use pm::bridge::{
     server, DelimSpan, Diagnostic, ExpnGlobals, Group, Ident, LitKind, Literal, Punct, TokenTree,
 };
-use pm::{Delimiter, Level};
+use pm::{Applicability, Delimiter, Level};
 use rustc_ast as ast;
 use rustc_ast::token;
 use rustc_ast::tokenstream::{self, DelimSpacing, Spacing, TokenStream};
@@ -388,6 +388,18 @@ fn to_internal(self) -> rustc_errors::Level {
     }
 }
 
+impl ToInternal<rustc_errors::Applicability> for Applicability {
+    fn to_internal(self) -> rustc_errors::Applicability {
+        match self {
+            Applicability::MachineApplicable => rustc_errors::Applicability::MachineApplicable,
+            Applicability::MaybeIncorrect => rustc_errors::Applicability::MaybeIncorrect,
+            Applicability::HasPlaceholders => rustc_errors::Applicability::HasPlaceholders,
+            Applicability::Unspecified => rustc_errors::Applicability::Unspecified,
+            _ => unreachable!("unknown proc_macro::Applicability variant: {:?}", self),
+        }
+    }
+}
+
 pub struct FreeFunctions;
 
 pub(crate) struct Rustc<'a, 'b> {
@@ -498,8 +510,20 @@ fn emit_diagnostic(&mut self, diagnostic: Diagnostic<Self::Span>) {
         let mut diag =
             rustc_errors::Diagnostic::new(diagnostic.level.to_internal(), diagnostic.message);
         diag.set_span(MultiSpan::from_spans(diagnostic.spans));
+        // Subdiagnostics cannot carry suggestions of their own, so the suggestions of children
+        // are attached to the diagnostic itself.
+        let mut suggestions = diagnostic.suggestions;
         for child in diagnostic.children {
             diag.sub(child.level.to_internal(), child.message, MultiSpan::from_spans(child.spans));
+            suggestions.extend(child.suggestions);
+        }
+        for suggestion in suggestions {
+            diag.span_suggestion(
+                suggestion.span,
+                suggestion.message,
+                suggestion.replacement,
+                suggestion.applicability.to_internal(),
+            );
         }
         self.sess().dcx.emit_diagnostic(diag);
     }
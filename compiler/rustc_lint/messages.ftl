@@ -351,6 +351,14 @@ lint_non_binding_let_multi_suggestion =
 lint_non_binding_let_on_drop_type =
     non-binding let on a type that implements `Drop`
 
+lint_non_binding_let_on_must_use =
+    non-binding let on the return value of `{$def}`, which must be used
+    .help = use the value, or pass it to `drop` to make discarding it explicit
+
+lint_non_binding_let_on_must_use_ty =
+    non-binding let on a value of type `{$ty}`, which must be used
+    .help = use the value, or pass it to `drop` to make discarding it explicit
+
 lint_non_binding_let_on_sync_lock =
     non-binding let on a synchronization lock
 
@@ -498,6 +506,7 @@ lint_supertrait_as_deref_target = this `Deref` implementation is covered by an i
     .label = `{$self_ty}` implements `Deref<Target = dyn {$target_principal}>` which conflicts with supertrait `{$supertrait_principal}`
     .label2 = target type is a supertrait of `{$self_ty}`
     .help = consider removing this implementation or replacing it with a method instead
+    .suggestion = replace the implementation with a method that upcasts explicitly
 
 lint_suspicious_double_ref_clone =
     using `.clone()` on a double reference, which returns `{$ty}` instead of cloning the inner type
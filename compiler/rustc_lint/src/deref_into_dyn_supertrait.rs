@@ -1,9 +1,13 @@
 use crate::{
-    lints::{SupertraitAsDerefTarget, SupertraitAsDerefTargetLabel},
+    lints::{
+        SupertraitAsDerefTarget, SupertraitAsDerefTargetLabel, SupertraitAsDerefTargetSuggestion,
+    },
+    nonstandard_style::NonSnakeCase,
     LateContext, LateLintPass, LintContext,
 };
 
 use rustc_hir as hir;
+use rustc_hir::def_id::DefId;
 use rustc_middle::ty;
 use rustc_span::sym;
 use rustc_trait_selection::traits::supertraits;
@@ -77,20 +81,68 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx hir::Item<'tcx>) {
                 .iter()
                 .find_map(|i| (i.ident.name == sym::Target).then_some(i.span))
                 .map(|label| SupertraitAsDerefTargetLabel { label });
+            let supertrait_principal = supertrait_principal
+                .map_bound(|trait_ref| ty::ExistentialTraitRef::erase_self_ty(tcx, trait_ref));
+            // Only suggest replacing the impl if it really is an upcast, i.e. if the target is
+            // exactly the supertrait the implicit coercion would produce. Inherent impls on
+            // `dyn Trait` are only allowed in the crate defining `Trait`.
+            let suggestion =
+                if supertrait_principal == target_principal && self_principal.def_id().is_local() {
+                    upcast_suggestion(cx, item, impl_, target_principal.def_id())
+                } else {
+                    None
+                };
             let span = tcx.def_span(item.owner_id.def_id);
             cx.emit_spanned_lint(
                 DEREF_INTO_DYN_SUPERTRAIT,
                 span,
                 SupertraitAsDerefTarget {
                     self_ty,
-                    supertrait_principal: supertrait_principal.map_bound(|trait_ref| {
-                        ty::ExistentialTraitRef::erase_self_ty(tcx, trait_ref)
-                    }),
+                    supertrait_principal,
                     target_principal,
                     label: span,
                     label2,
+                    suggestion,
                 },
             );
         }
     }
 }
+
+/// Builds an inherent impl with a method that performs the upcast of the `Deref` impl explicitly.
+/// The types and the where clause are taken from the source, so that any lifetimes and bounds in
+/// them are kept as written. The method is `pub` as the `Deref` impl it replaces was usable from
+/// wherever the trait object type is.
+fn upcast_suggestion(
+    cx: &LateContext<'_>,
+    item: &hir::Item<'_>,
+    impl_: &hir::Impl<'_>,
+    target_def_id: DefId,
+) -> Option<SupertraitAsDerefTargetSuggestion> {
+    let sm = cx.sess().source_map();
+    let target = impl_.items.iter().find(|i| i.ident.name == sym::Target)?;
+    let hir::ImplItemKind::Type(target_ty) = cx.tcx.hir().impl_item(target.id).kind else {
+        return None;
+    };
+    let generics = sm.span_to_snippet(impl_.generics.span).ok()?;
+    let self_ty = sm.span_to_snippet(impl_.self_ty.span).ok()?;
+    // Keep the bounds of the impl, and lay them out the way rustfmt would.
+    let where_clause = if impl_.generics.has_where_clause_predicates {
+        format!("\n{}\n", sm.span_to_snippet(impl_.generics.where_clause_span).ok()?)
+    } else {
+        " ".to_string()
+    };
+    let mut target_ty = sm.span_to_snippet(target_ty.span).ok()?;
+    // `&dyn A + 'a` does not parse, the object type needs to be parenthesized.
+    if target_ty.contains('+') {
+        target_ty = format!("({target_ty})");
+    }
+    let method =
+        format!("as_dyn_{}", NonSnakeCase::to_snake_case(cx.tcx.item_name(target_def_id).as_str()));
+    Some(SupertraitAsDerefTargetSuggestion {
+        span: item.span,
+        code: format!(
+            "impl{generics} {self_ty}{where_clause}{{\n    pub fn {method}(&self) -> &{target_ty} {{\n        self as &{target_ty}\n    }}\n}}"
+        ),
+    })
+}
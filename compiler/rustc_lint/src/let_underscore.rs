@@ -1,11 +1,13 @@
 use crate::{
-    lints::{NonBindingLet, NonBindingLetSub},
+    lints::{NonBindingLet, NonBindingLetMustUse, NonBindingLetSub},
+    unused::is_ty_must_use,
     LateContext, LateLintPass, LintContext,
 };
 use rustc_errors::MultiSpan;
 use rustc_hir as hir;
+use rustc_hir::def::{DefKind, Res};
 use rustc_middle::ty;
-use rustc_span::Symbol;
+use rustc_span::{sym, Symbol};
 
 declare_lint! {
     /// The `let_underscore_drop` lint checks for statements which don't bind
@@ -95,7 +97,52 @@
     "non-binding let on a synchronization lock"
 }
 
-declare_lint_pass!(LetUnderscore => [LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK]);
+declare_lint! {
+    /// The `let_underscore_must_use` lint checks for statements which don't
+    /// bind the return value of a function marked `#[must_use]`, or a value of
+    /// a `#[must_use]` type, to anything.
+    ///
+    /// ### Example
+    ///
+    /// ```rust,compile_fail
+    /// #![deny(let_underscore_must_use)]
+    ///
+    /// #[must_use = "the checksum should be compared against the expected one"]
+    /// fn checksum(data: &[u8]) -> u32 {
+    ///     data.iter().map(|&b| u32::from(b)).sum()
+    /// }
+    ///
+    /// fn main() {
+    ///     let _ = checksum(b"hello");
+    /// }
+    /// ```
+    ///
+    /// {{produces}}
+    ///
+    /// ### Explanation
+    ///
+    /// Assigning a value to `_` silences the [`unused_must_use` lint], which
+    /// makes it easy to throw away a value that was meant to be checked without
+    /// noticing. This lint treats such statements like expressions whose value
+    /// is not used at all, so it covers the same values as `unused_must_use`:
+    /// calls to `#[must_use]` functions, and values of `#[must_use]` types such
+    /// as `Result`, including those nested in boxes, tuples and arrays or
+    /// returned as `impl Trait` of a `#[must_use]` trait. Every place where such
+    /// a value is discarded then has to be spelled out, for example by passing
+    /// the value to `std::mem::drop`.
+    ///
+    /// This lint is "allow" by default because `let _ = ...` is the usual way
+    /// to intentionally ignore a `#[must_use]` value.
+    ///
+    /// [`unused_must_use` lint]: warn-by-default.html#unused-must-use
+    pub LET_UNDERSCORE_MUST_USE,
+    Allow,
+    "non-binding let on a value that must be used"
+}
+
+declare_lint_pass!(
+    LetUnderscore => [LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK, LET_UNDERSCORE_MUST_USE]
+);
 
 const SYNC_GUARD_SYMBOLS: [Symbol; 3] = [
     rustc_span::sym::MutexGuard,
@@ -109,6 +156,8 @@ fn check_local(&mut self, cx: &LateContext<'_>, local: &hir::Local<'_>) {
             return;
         }
         if let Some(init) = local.init {
+            check_must_use(cx, local, init);
+
             let init_ty = cx.typeck_results().expr_ty(init);
             // If the type has a trivial Drop implementation, then it doesn't
             // matter that we drop the value immediately.
@@ -148,3 +197,40 @@ fn check_local(&mut self, cx: &LateContext<'_>, local: &hir::Local<'_>) {
         }
     }
 }
+
+/// Lints `let _ = init` where `init` is a call to a `#[must_use]` function, or a value whose type
+/// `unused_must_use` would lint if it were left unused.
+fn check_must_use(cx: &LateContext<'_>, local: &hir::Local<'_>, init: &hir::Expr<'_>) {
+    let def_id = match init.kind {
+        hir::ExprKind::Call(callee, _) => match callee.kind {
+            hir::ExprKind::Path(ref qpath) => match cx.qpath_res(qpath, callee.hir_id) {
+                Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) => Some(def_id),
+                _ => None,
+            },
+            _ => None,
+        },
+        hir::ExprKind::MethodCall(..) => cx.typeck_results().type_dependent_def_id(init.hir_id),
+        _ => None,
+    };
+    if let Some(def_id) = def_id
+        && let Some(attr) = cx.tcx.get_attr(def_id, sym::must_use)
+    {
+        cx.emit_spanned_lint(
+            LET_UNDERSCORE_MUST_USE,
+            local.span,
+            NonBindingLetMustUse::Fn { def: cx.tcx.def_path_str(def_id), note: attr.value_str() },
+        );
+        return;
+    }
+
+    let ty = cx.typeck_results().expr_ty(init);
+    if let Some(path) = is_ty_must_use(cx, ty, init, init.span)
+        && let Some((_, note)) = path.root_def()
+    {
+        cx.emit_spanned_lint(
+            LET_UNDERSCORE_MUST_USE,
+            local.span,
+            NonBindingLetMustUse::Ty { ty, note },
+        );
+    }
+}
@@ -299,7 +299,12 @@ macro_rules! add_lint_group {
         MAP_UNIT_FN
     );
 
-    add_lint_group!("let_underscore", LET_UNDERSCORE_DROP, LET_UNDERSCORE_LOCK);
+    add_lint_group!(
+        "let_underscore",
+        LET_UNDERSCORE_DROP,
+        LET_UNDERSCORE_LOCK,
+        LET_UNDERSCORE_MUST_USE
+    );
 
     add_lint_group!(
         "rust_2018_idioms",
@@ -553,6 +553,8 @@ pub struct SupertraitAsDerefTarget<'a> {
     pub label: Span,
     #[subdiagnostic]
     pub label2: Option<SupertraitAsDerefTargetLabel>,
+    #[subdiagnostic]
+    pub suggestion: Option<SupertraitAsDerefTargetSuggestion>,
 }
 
 #[derive(Subdiagnostic)]
@@ -562,6 +564,19 @@ pub struct SupertraitAsDerefTargetLabel {
     pub label: Span,
 }
 
+#[derive(Subdiagnostic)]
+#[suggestion(
+    lint_suggestion,
+    style = "verbose",
+    code = "{code}",
+    applicability = "maybe-incorrect"
+)]
+pub struct SupertraitAsDerefTargetSuggestion {
+    #[primary_span]
+    pub span: Span,
+    pub code: String,
+}
+
 // enum_intrinsics_non_enums.rs
 #[derive(LintDiagnostic)]
 #[diag(lint_enum_intrinsics_mem_discriminant)]
@@ -977,6 +992,38 @@ fn add_to_diagnostic_with<F>(self, diag: &mut rustc_errors::Diagnostic, _: F)
     }
 }
 
+pub enum NonBindingLetMustUse<'a> {
+    Fn { def: String, note: Option<Symbol> },
+    Ty { ty: Ty<'a>, note: Option<Symbol> },
+}
+
+impl<'a> DecorateLint<'a, ()> for NonBindingLetMustUse<'_> {
+    fn decorate_lint<'b>(self, diag: &'b mut rustc_errors::DiagnosticBuilder<'a, ()>) {
+        let note = match self {
+            NonBindingLetMustUse::Fn { def, note } => {
+                diag.set_arg("def", def);
+                note
+            }
+            NonBindingLetMustUse::Ty { ty, note } => {
+                diag.set_arg("ty", ty);
+                note
+            }
+        };
+        // check for #[must_use = "..."]
+        if let Some(note) = note {
+            diag.note(note.to_string());
+        }
+        diag.help(fluent::lint_help);
+    }
+
+    fn msg(&self) -> DiagnosticMessage {
+        match self {
+            NonBindingLetMustUse::Fn { .. } => fluent::lint_non_binding_let_on_must_use,
+            NonBindingLetMustUse::Ty { .. } => fluent::lint_non_binding_let_on_must_use_ty,
+        }
+    }
+}
+
 // levels.rs
 #[derive(LintDiagnostic)]
 #[diag(lint_overruled_attribute)]
@@ -234,7 +234,7 @@ fn check_generic_param(&mut self, cx: &EarlyContext<'_>, param: &ast::GenericPar
 declare_lint_pass!(NonSnakeCase => [NON_SNAKE_CASE]);
 
 impl NonSnakeCase {
-    fn to_snake_case(mut str: &str) -> String {
+    pub(crate) fn to_snake_case(mut str: &str) -> String {
         let mut words = vec![];
         // Preserve leading underscores
         str = str.trim_start_matches(|c: char| {
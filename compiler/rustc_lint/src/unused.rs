@@ -243,144 +243,6 @@ fn check_fn_must_use(
             }
         }
 
-        /// A path through a type to a must_use source. Contains useful info for the lint.
-        #[derive(Debug)]
-        enum MustUsePath {
-            /// Suppress must_use checking.
-            Suppressed,
-            /// The root of the normal must_use lint with an optional message.
-            Def(Span, DefId, Option<Symbol>),
-            Boxed(Box<Self>),
-            Pinned(Box<Self>),
-            Opaque(Box<Self>),
-            TraitObject(Box<Self>),
-            TupleElement(Vec<(usize, Self)>),
-            Array(Box<Self>, u64),
-            /// The root of the unused_closures lint.
-            Closure(Span),
-            /// The root of the unused_coroutines lint.
-            Coroutine(Span),
-        }
-
-        #[instrument(skip(cx, expr), level = "debug", ret)]
-        fn is_ty_must_use<'tcx>(
-            cx: &LateContext<'tcx>,
-            ty: Ty<'tcx>,
-            expr: &hir::Expr<'_>,
-            span: Span,
-        ) -> Option<MustUsePath> {
-            if ty.is_unit()
-                || !ty.is_inhabited_from(
-                    cx.tcx,
-                    cx.tcx.parent_module(expr.hir_id).to_def_id(),
-                    cx.param_env,
-                )
-            {
-                return Some(MustUsePath::Suppressed);
-            }
-
-            match *ty.kind() {
-                ty::Adt(..) if ty.is_box() => {
-                    let boxed_ty = ty.boxed_ty();
-                    is_ty_must_use(cx, boxed_ty, expr, span)
-                        .map(|inner| MustUsePath::Boxed(Box::new(inner)))
-                }
-                ty::Adt(def, args) if cx.tcx.lang_items().pin_type() == Some(def.did()) => {
-                    let pinned_ty = args.type_at(0);
-                    is_ty_must_use(cx, pinned_ty, expr, span)
-                        .map(|inner| MustUsePath::Pinned(Box::new(inner)))
-                }
-                ty::Adt(def, _) => is_def_must_use(cx, def.did(), span),
-                ty::Alias(ty::Opaque | ty::Projection, ty::AliasTy { def_id: def, .. }) => {
-                    elaborate(
-                        cx.tcx,
-                        cx.tcx.explicit_item_bounds(def).instantiate_identity_iter_copied(),
-                    )
-                    // We only care about self bounds for the impl-trait
-                    .filter_only_self()
-                    .find_map(|(pred, _span)| {
-                        // We only look at the `DefId`, so it is safe to skip the binder here.
-                        if let ty::ClauseKind::Trait(ref poly_trait_predicate) =
-                            pred.kind().skip_binder()
-                        {
-                            let def_id = poly_trait_predicate.trait_ref.def_id;
-
-                            is_def_must_use(cx, def_id, span)
-                        } else {
-                            None
-                        }
-                    })
-                    .map(|inner| MustUsePath::Opaque(Box::new(inner)))
-                }
-                ty::Dynamic(binders, _, _) => binders.iter().find_map(|predicate| {
-                    if let ty::ExistentialPredicate::Trait(ref trait_ref) = predicate.skip_binder()
-                    {
-                        let def_id = trait_ref.def_id;
-                        is_def_must_use(cx, def_id, span)
-                            .map(|inner| MustUsePath::TraitObject(Box::new(inner)))
-                    } else {
-                        None
-                    }
-                }),
-                ty::Tuple(tys) => {
-                    let elem_exprs = if let hir::ExprKind::Tup(elem_exprs) = expr.kind {
-                        debug_assert_eq!(elem_exprs.len(), tys.len());
-                        elem_exprs
-                    } else {
-                        &[]
-                    };
-
-                    // Default to `expr`.
-                    let elem_exprs = elem_exprs.iter().chain(iter::repeat(expr));
-
-                    let nested_must_use = tys
-                        .iter()
-                        .zip(elem_exprs)
-                        .enumerate()
-                        .filter_map(|(i, (ty, expr))| {
-                            is_ty_must_use(cx, ty, expr, expr.span).map(|path| (i, path))
-                        })
-                        .collect::<Vec<_>>();
-
-                    if !nested_must_use.is_empty() {
-                        Some(MustUsePath::TupleElement(nested_must_use))
-                    } else {
-                        None
-                    }
-                }
-                ty::Array(ty, len) => match len.try_eval_target_usize(cx.tcx, cx.param_env) {
-                    // If the array is empty we don't lint, to avoid false positives
-                    Some(0) | None => None,
-                    // If the array is definitely non-empty, we can do `#[must_use]` checking.
-                    Some(len) => is_ty_must_use(cx, ty, expr, span)
-                        .map(|inner| MustUsePath::Array(Box::new(inner), len)),
-                },
-                ty::Closure(..) => Some(MustUsePath::Closure(span)),
-                ty::Coroutine(def_id, ..) => {
-                    // async fn should be treated as "implementor of `Future`"
-                    let must_use = if cx.tcx.coroutine_is_async(def_id) {
-                        let def_id = cx.tcx.lang_items().future_trait()?;
-                        is_def_must_use(cx, def_id, span)
-                            .map(|inner| MustUsePath::Opaque(Box::new(inner)))
-                    } else {
-                        None
-                    };
-                    must_use.or(Some(MustUsePath::Coroutine(span)))
-                }
-                _ => None,
-            }
-        }
-
-        fn is_def_must_use(cx: &LateContext<'_>, def_id: DefId, span: Span) -> Option<MustUsePath> {
-            if let Some(attr) = cx.tcx.get_attr(def_id, sym::must_use) {
-                // check for #[must_use = "..."]
-                let reason = attr.value_str();
-                Some(MustUsePath::Def(span, def_id, reason))
-            } else {
-                None
-            }
-        }
-
         // Returns whether further errors should be suppressed because either a lint has been emitted or the type should be ignored.
         fn check_must_use_def(
             cx: &LateContext<'_>,
@@ -533,6 +395,156 @@ fn emit_must_use_untranslated(
     }
 }
 
+/// A path through a type to a must_use source. Contains useful info for the lint.
+#[derive(Debug)]
+pub(crate) enum MustUsePath {
+    /// Suppress must_use checking.
+    Suppressed,
+    /// The root of the normal must_use lint with an optional message.
+    Def(Span, DefId, Option<Symbol>),
+    Boxed(Box<Self>),
+    Pinned(Box<Self>),
+    Opaque(Box<Self>),
+    TraitObject(Box<Self>),
+    TupleElement(Vec<(usize, Self)>),
+    Array(Box<Self>, u64),
+    /// The root of the unused_closures lint.
+    Closure(Span),
+    /// The root of the unused_coroutines lint.
+    Coroutine(Span),
+}
+
+impl MustUsePath {
+    /// The first `#[must_use]` definition this path leads to, along with the message of its
+    /// attribute. Closures and coroutines are not `#[must_use]` definitions, so they have none.
+    pub(crate) fn root_def(&self) -> Option<(DefId, Option<Symbol>)> {
+        match self {
+            MustUsePath::Suppressed | MustUsePath::Closure(_) | MustUsePath::Coroutine(_) => None,
+            MustUsePath::Def(_, def_id, reason) => Some((*def_id, *reason)),
+            MustUsePath::Boxed(path)
+            | MustUsePath::Pinned(path)
+            | MustUsePath::Opaque(path)
+            | MustUsePath::TraitObject(path)
+            | MustUsePath::Array(path, _) => path.root_def(),
+            MustUsePath::TupleElement(elems) => elems.iter().find_map(|(_, path)| path.root_def()),
+        }
+    }
+}
+
+#[instrument(skip(cx, expr), level = "debug", ret)]
+pub(crate) fn is_ty_must_use<'tcx>(
+    cx: &LateContext<'tcx>,
+    ty: Ty<'tcx>,
+    expr: &hir::Expr<'_>,
+    span: Span,
+) -> Option<MustUsePath> {
+    if ty.is_unit()
+        || !ty.is_inhabited_from(
+            cx.tcx,
+            cx.tcx.parent_module(expr.hir_id).to_def_id(),
+            cx.param_env,
+        )
+    {
+        return Some(MustUsePath::Suppressed);
+    }
+
+    match *ty.kind() {
+        ty::Adt(..) if ty.is_box() => {
+            let boxed_ty = ty.boxed_ty();
+            is_ty_must_use(cx, boxed_ty, expr, span)
+                .map(|inner| MustUsePath::Boxed(Box::new(inner)))
+        }
+        ty::Adt(def, args) if cx.tcx.lang_items().pin_type() == Some(def.did()) => {
+            let pinned_ty = args.type_at(0);
+            is_ty_must_use(cx, pinned_ty, expr, span)
+                .map(|inner| MustUsePath::Pinned(Box::new(inner)))
+        }
+        ty::Adt(def, _) => is_def_must_use(cx, def.did(), span),
+        ty::Alias(ty::Opaque | ty::Projection, ty::AliasTy { def_id: def, .. }) => {
+            elaborate(cx.tcx, cx.tcx.explicit_item_bounds(def).instantiate_identity_iter_copied())
+                // We only care about self bounds for the impl-trait
+                .filter_only_self()
+                .find_map(|(pred, _span)| {
+                    // We only look at the `DefId`, so it is safe to skip the binder here.
+                    if let ty::ClauseKind::Trait(ref poly_trait_predicate) =
+                        pred.kind().skip_binder()
+                    {
+                        let def_id = poly_trait_predicate.trait_ref.def_id;
+
+                        is_def_must_use(cx, def_id, span)
+                    } else {
+                        None
+                    }
+                })
+                .map(|inner| MustUsePath::Opaque(Box::new(inner)))
+        }
+        ty::Dynamic(binders, _, _) => binders.iter().find_map(|predicate| {
+            if let ty::ExistentialPredicate::Trait(ref trait_ref) = predicate.skip_binder() {
+                let def_id = trait_ref.def_id;
+                is_def_must_use(cx, def_id, span)
+                    .map(|inner| MustUsePath::TraitObject(Box::new(inner)))
+            } else {
+                None
+            }
+        }),
+        ty::Tuple(tys) => {
+            let elem_exprs = if let hir::ExprKind::Tup(elem_exprs) = expr.kind {
+                debug_assert_eq!(elem_exprs.len(), tys.len());
+                elem_exprs
+            } else {
+                &[]
+            };
+
+            // Default to `expr`.
+            let elem_exprs = elem_exprs.iter().chain(iter::repeat(expr));
+
+            let nested_must_use = tys
+                .iter()
+                .zip(elem_exprs)
+                .enumerate()
+                .filter_map(|(i, (ty, expr))| {
+                    is_ty_must_use(cx, ty, expr, expr.span).map(|path| (i, path))
+                })
+                .collect::<Vec<_>>();
+
+            if !nested_must_use.is_empty() {
+                Some(MustUsePath::TupleElement(nested_must_use))
+            } else {
+                None
+            }
+        }
+        ty::Array(ty, len) => match len.try_eval_target_usize(cx.tcx, cx.param_env) {
+            // If the array is empty we don't lint, to avoid false positives
+            Some(0) | None => None,
+            // If the array is definitely non-empty, we can do `#[must_use]` checking.
+            Some(len) => is_ty_must_use(cx, ty, expr, span)
+                .map(|inner| MustUsePath::Array(Box::new(inner), len)),
+        },
+        ty::Closure(..) => Some(MustUsePath::Closure(span)),
+        ty::Coroutine(def_id, ..) => {
+            // async fn should be treated as "implementor of `Future`"
+            let must_use = if cx.tcx.coroutine_is_async(def_id) {
+                let def_id = cx.tcx.lang_items().future_trait()?;
+                is_def_must_use(cx, def_id, span).map(|inner| MustUsePath::Opaque(Box::new(inner)))
+            } else {
+                None
+            };
+            must_use.or(Some(MustUsePath::Coroutine(span)))
+        }
+        _ => None,
+    }
+}
+
+fn is_def_must_use(cx: &LateContext<'_>, def_id: DefId, span: Span) -> Option<MustUsePath> {
+    if let Some(attr) = cx.tcx.get_attr(def_id, sym::must_use) {
+        // check for #[must_use = "..."]
+        let reason = attr.value_str();
+        Some(MustUsePath::Def(span, def_id, reason))
+    } else {
+        None
+    }
+}
+
 declare_lint! {
     /// The `path_statements` lint detects path statements with no effect.
     ///
@@ -1703,9 +1703,11 @@ pub fn multi(a: S, b: S, c: S, d: S) -> R {
 static EDITION_STRING: LazyLock<String> = LazyLock::new(|| {
     format!(
         "Specify which edition of the compiler to use when compiling code. \
-The default is {DEFAULT_EDITION} and the latest stable edition is {LATEST_STABLE_EDITION}."
+The default is {DEFAULT_EDITION} and the latest stable edition is {LATEST_STABLE_EDITION}. \
+`future` is the edition in development and requires `-Z unstable-options`."
     )
 });
+static EDITION_HINT: LazyLock<String> = LazyLock::new(|| format!("{EDITION_NAME_LIST}|future"));
 /// Returns the "short" subset of the rustc command line options,
 /// including metadata for each option, such as whether the option is
 /// part of the stable long-term interface for rustc.
@@ -1740,7 +1742,7 @@ pub fn rustc_short_optgroups() -> Vec<RustcOptGroup> {
             "",
             "edition",
             &EDITION_STRING,
-            EDITION_NAME_LIST,
+            &EDITION_HINT,
         ),
         opt::multi_s(
             "",
@@ -2022,10 +2024,14 @@ pub fn parse_error_format(
 
 pub fn parse_crate_edition(early_dcx: &EarlyDiagCtxt, matches: &getopts::Matches) -> Edition {
     let edition = match matches.opt_str("edition") {
+        Some(arg) if arg == "future" => Edition::future().unwrap_or_else(|| {
+            early_dcx
+                .early_fatal("`--edition future` is not available: no edition is in development")
+        }),
         Some(arg) => Edition::from_str(&arg).unwrap_or_else(|_| {
             early_dcx.early_fatal(format!(
                 "argument for `--edition` must be one of: \
-                     {EDITION_NAME_LIST}. (instead was `{arg}`)"
+                     {EDITION_NAME_LIST}|future. (instead was `{arg}`)"
             ))
         }),
         None => DEFAULT_EDITION,
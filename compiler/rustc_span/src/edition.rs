@@ -66,6 +66,12 @@ pub fn is_stable(self) -> bool {
         }
     }
 
+    /// The newest edition, if it is still under development. This is the edition selected by
+    /// `--edition future`.
+    pub fn future() -> Option<Edition> {
+        ALL_EDITIONS.last().copied().filter(|edition| !edition.is_stable())
+    }
+
     /// Is this edition 2015?
     pub fn is_rust_2015(self) -> bool {
         self == Edition::Edition2015
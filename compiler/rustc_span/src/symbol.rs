@@ -169,6 +169,7 @@
         Center,
         Cleanup,
         Clone,
+        CoerceUnsized,
         Command,
         ConstParamTy,
         Context,
@@ -184,6 +185,7 @@
         Deref,
         DiagnosticMessage,
         DirBuilder,
+        DispatchFromDyn,
         Display,
         DoubleEndedIterator,
         Duration,
@@ -303,8 +305,10 @@
         Saturating,
         Send,
         SeqCst,
+        Sized,
         SliceIndex,
         SliceIter,
+        SmartPointer,
         Some,
         SpanCtxt,
         String,
@@ -327,6 +331,7 @@
         TyCtxt,
         TyKind,
         Unknown,
+        Unsize,
         Vec,
         VecDeque,
         Wrapper,
@@ -843,6 +848,7 @@
         hash,
         hexagon_target_feature,
         hidden,
+        hint_assert_unchecked,
         homogeneous_aggregate,
         host,
         html_favicon_url,
@@ -1154,6 +1160,7 @@
         on,
         on_unimplemented,
         opaque,
+        ops,
         opt_out_copy,
         optimize,
         optimize_attribute,
@@ -1207,6 +1214,7 @@
         plugin,
         plugin_registrar,
         plugins,
+        pointee,
         pointee_trait,
         pointer,
         pointer_like,
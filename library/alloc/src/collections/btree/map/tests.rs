@@ -1358,6 +1358,29 @@ fn test_entry() {
     map.check();
 }
 
+#[test]
+fn test_entry_or_try_insert_with() {
+    let mut map = BTreeMap::from([(1, 10)]);
+
+    // Existing key: the default function is not called
+    assert_eq!(
+        map.entry(1).or_try_insert_with(|| -> Result<_, ()> { unreachable!() }),
+        Ok(&mut 10)
+    );
+
+    // Inexistent key, failing default function: the entry stays empty
+    assert_eq!(map.entry(2).or_try_insert_with(|| Err("nope")), Err("nope"));
+    assert_eq!(map.get(&2), None);
+    assert_eq!(map.len(), 1);
+    map.check();
+
+    // Inexistent key, succeeding default function
+    assert_eq!(map.entry(2).or_try_insert_with(|| Ok::<_, ()>(20)), Ok(&mut 20));
+    assert_eq!(map.get(&2), Some(&20));
+    assert_eq!(map.len(), 2);
+    map.check();
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = BTreeMap::new();
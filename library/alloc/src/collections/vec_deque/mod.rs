@@ -1076,6 +1076,74 @@ fn drop(&mut self) {
         }
     }
 
+    /// Shortens the deque, keeping the last `len` elements and dropping
+    /// the rest.
+    ///
+    /// If `len` is greater or equal to the deque's current length, this has
+    /// no effect.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vec_deque_truncate_front)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf = VecDeque::new();
+    /// buf.push_back(5);
+    /// buf.push_back(10);
+    /// buf.push_back(15);
+    /// assert_eq!(buf, [5, 10, 15]);
+    /// buf.truncate_front(1);
+    /// assert_eq!(buf, [15]);
+    /// ```
+    #[unstable(feature = "vec_deque_truncate_front", issue = "none")]
+    pub fn truncate_front(&mut self, len: usize) {
+        /// Runs the destructor for all items in the slice when it gets dropped (normally or
+        /// during unwinding).
+        struct Dropper<'a, T>(&'a mut [T]);
+
+        impl<'a, T> Drop for Dropper<'a, T> {
+            fn drop(&mut self) {
+                unsafe {
+                    ptr::drop_in_place(self.0);
+                }
+            }
+        }
+
+        // Safe because:
+        //
+        // * Any slice passed to `drop_in_place` is valid; the second case has
+        //   `drop_len <= front.len()` and returning on `len > self.len()` ensures
+        //   `end <= back.len()` in the first case
+        // * The head of the VecDeque is moved before calling `drop_in_place`,
+        //   so no value is dropped twice if `drop_in_place` panics
+        unsafe {
+            if len >= self.len {
+                return;
+            }
+
+            let drop_len = self.len - len;
+            let (front, back) = self.as_mut_slices();
+            if drop_len > front.len() {
+                let end = drop_len - front.len();
+                let drop_front = front as *mut _;
+                let drop_back = back.get_unchecked_mut(..end) as *mut _;
+                self.head = self.to_physical_idx(drop_len);
+                self.len = len;
+
+                // Make sure the second half is dropped even when a destructor
+                // in the first one panics.
+                let _back_dropper = Dropper(&mut *drop_back);
+                ptr::drop_in_place(drop_front);
+            } else {
+                let drop_front = front.get_unchecked_mut(..drop_len) as *mut _;
+                self.head = self.to_physical_idx(drop_len);
+                self.len = len;
+                ptr::drop_in_place(drop_front);
+            }
+        }
+    }
+
     /// Returns a reference to the underlying allocator.
     #[unstable(feature = "allocator_api", issue = "32838")]
     #[inline]
@@ -2369,6 +2437,93 @@ pub fn rotate_right(&mut self, n: usize) {
         }
     }
 
+    /// Rotates the elements in `range` `mid` places to the left, leaving the
+    /// elements outside of `range` in place.
+    ///
+    /// After calling `rotate_left_range`, the element previously at index
+    /// `range.start + mid` becomes the first element of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, if the end
+    /// point is greater than the length of the deque, or if `mid` is greater
+    /// than the length of the range. Note that `mid` equal to the length of the
+    /// range does _not_ panic and is a no-op rotation.
+    ///
+    /// # Complexity
+    ///
+    /// Takes linear (in the length of the range) time and no extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_rotate_range)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = (0..10).collect();
+    ///
+    /// buf.rotate_left_range(2..7, 2);
+    /// assert_eq!(buf, [0, 1, 4, 5, 6, 2, 3, 7, 8, 9]);
+    /// ```
+    #[unstable(feature = "vecdeque_rotate_range", issue = "none")]
+    pub fn rotate_left_range<R>(&mut self, range: R, mid: usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = slice::range(range, ..self.len);
+        assert!(mid <= end - start);
+        self.reverse_range(start, start + mid);
+        self.reverse_range(start + mid, end);
+        self.reverse_range(start, end);
+    }
+
+    /// Rotates the elements in `range` `k` places to the right, leaving the
+    /// elements outside of `range` in place.
+    ///
+    /// After calling `rotate_right_range`, the element previously at index
+    /// `range.end - k` becomes the first element of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point, if the end
+    /// point is greater than the length of the deque, or if `k` is greater
+    /// than the length of the range. Note that `k` equal to the length of the
+    /// range does _not_ panic and is a no-op rotation.
+    ///
+    /// # Complexity
+    ///
+    /// Takes linear (in the length of the range) time and no extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(vecdeque_rotate_range)]
+    /// use std::collections::VecDeque;
+    ///
+    /// let mut buf: VecDeque<_> = (0..10).collect();
+    ///
+    /// buf.rotate_right_range(2..7, 2);
+    /// assert_eq!(buf, [0, 1, 5, 6, 2, 3, 4, 7, 8, 9]);
+    /// ```
+    #[unstable(feature = "vecdeque_rotate_range", issue = "none")]
+    pub fn rotate_right_range<R>(&mut self, range: R, k: usize)
+    where
+        R: RangeBounds<usize>,
+    {
+        let Range { start, end } = slice::range(range, ..self.len);
+        assert!(k <= end - start);
+        self.rotate_left_range(start..end, end - start - k);
+    }
+
+    /// Reverses the elements in `start..end`, which must be in bounds.
+    fn reverse_range(&mut self, mut start: usize, mut end: usize) {
+        while end - start > 1 {
+            end -= 1;
+            self.swap(start, end);
+            start += 1;
+        }
+    }
+
     // SAFETY: the following two methods require that the rotation amount
     // be less than half the length of the deque.
     //
@@ -364,6 +364,49 @@ fn test_rotate_right_panic() {
     tester.rotate_right(tester.len() + 1);
 }
 
+#[test]
+fn test_rotate_range() {
+    // Make the deque wrap around the end of its buffer.
+    let mut tester: VecDeque<_> = VecDeque::with_capacity(10);
+    tester.extend(5..=10);
+    for i in (1..=4).rev() {
+        tester.push_front(i);
+    }
+    assert_eq!(tester, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    assert!(!tester.as_slices().1.is_empty());
+
+    tester.rotate_left_range(2..8, 2);
+    assert_eq!(tester, [1, 2, 5, 6, 7, 8, 3, 4, 9, 10]);
+
+    tester.rotate_right_range(2..8, 2);
+    assert_eq!(tester, [1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+
+    tester.rotate_left_range(.., 3);
+    assert_eq!(tester, [4, 5, 6, 7, 8, 9, 10, 1, 2, 3]);
+
+    tester.rotate_right_range(7.., 1);
+    assert_eq!(tester, [4, 5, 6, 7, 8, 9, 10, 3, 1, 2]);
+
+    tester.rotate_left_range(..=2, 0);
+    tester.rotate_left_range(3..3, 0);
+    tester.rotate_right_range(1..4, 3);
+    assert_eq!(tester, [4, 5, 6, 7, 8, 9, 10, 3, 1, 2]);
+}
+
+#[test]
+#[should_panic = "assertion failed: mid <= end - start"]
+fn test_rotate_left_range_panic() {
+    let mut tester: VecDeque<_> = (1..=10).collect();
+    tester.rotate_left_range(2..5, 4);
+}
+
+#[test]
+#[should_panic]
+fn test_rotate_right_range_out_of_bounds() {
+    let mut tester: VecDeque<_> = (1..=10).collect();
+    tester.rotate_right_range(5..11, 1);
+}
+
 #[test]
 fn test_binary_search() {
     // If the givin VecDeque is not sorted, the returned result is unspecified and meaningless,
@@ -1116,6 +1159,46 @@ fn drop(&mut self) {
     }
 }
 
+#[test]
+fn test_vec_deque_truncate_front() {
+    static mut DROPS: u32 = 0;
+    #[derive(Clone)]
+    struct Elem(i32);
+    impl Drop for Elem {
+        fn drop(&mut self) {
+            unsafe {
+                DROPS += 1;
+            }
+        }
+    }
+
+    let v = vec![Elem(1), Elem(2), Elem(3), Elem(4), Elem(5)];
+    for push_front in 0..=v.len() {
+        let v = v.clone();
+        let mut tester = VecDeque::with_capacity(5);
+        for (index, elem) in v.into_iter().enumerate() {
+            if index < push_front {
+                tester.push_front(elem);
+            } else {
+                tester.push_back(elem);
+            }
+        }
+        let expected: Vec<i32> = tester.iter().skip(2).map(|e| e.0).collect();
+        assert_eq!(unsafe { DROPS }, 0);
+        tester.truncate_front(3);
+        assert_eq!(unsafe { DROPS }, 2);
+        assert_eq!(tester.iter().map(|e| e.0).collect::<Vec<_>>(), expected);
+        tester.truncate_front(5);
+        assert_eq!(unsafe { DROPS }, 2);
+        tester.truncate_front(0);
+        assert_eq!(unsafe { DROPS }, 5);
+        assert!(tester.is_empty());
+        unsafe {
+            DROPS = 0;
+        }
+    }
+}
+
 #[test]
 fn issue_53529() {
     use crate::boxed::Box;
@@ -2216,6 +2216,32 @@ fn extend_one(&mut self, s: &'a str) {
     }
 }
 
+#[cfg(not(no_global_oom_handling))]
+#[stable(feature = "extend_ref_str", since = "CURRENT_RUSTC_VERSION")]
+impl<'a, 'b> Extend<&'b &'a str> for String {
+    fn extend<I: IntoIterator<Item = &'b &'a str>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+
+    #[inline]
+    fn extend_one(&mut self, &s: &'b &'a str) {
+        self.push_str(s);
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[stable(feature = "extend_ref_str", since = "CURRENT_RUSTC_VERSION")]
+impl<'a> Extend<&'a String> for String {
+    fn extend<I: IntoIterator<Item = &'a String>>(&mut self, iter: I) {
+        iter.into_iter().for_each(move |s| self.push_str(s));
+    }
+
+    #[inline]
+    fn extend_one(&mut self, s: &'a String) {
+        self.push_str(s);
+    }
+}
+
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "box_str2", since = "1.45.0")]
 impl Extend<Box<str>> for String {
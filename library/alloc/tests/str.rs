@@ -1094,6 +1094,27 @@ fn test_split_at_boundscheck() {
     let _ = s.split_at(1);
 }
 
+#[test]
+fn test_split_at_checked() {
+    let s = "ศไทย中华Việt Nam";
+    for (index, _) in s.char_indices() {
+        let (a, b) = s.split_at_checked(index).unwrap();
+        assert_eq!(&s[..a.len()], a);
+        assert_eq!(&s[a.len()..], b);
+    }
+    assert_eq!(s.split_at_checked(s.len()), Some((s, "")));
+    assert_eq!(s.split_at_checked(1), None);
+    assert_eq!(s.split_at_checked(s.len() + 1), None);
+
+    let mut s = "Hello World".to_string();
+    if let Some((a, b)) = s.split_at_mut_checked(5) {
+        a.make_ascii_uppercase();
+        b.make_ascii_lowercase();
+    }
+    assert_eq!(s, "HELLO world");
+    assert_eq!(s.split_at_mut_checked(12), None);
+}
+
 #[test]
 fn test_escape_unicode() {
     assert_eq!("abc".escape_unicode().to_string(), "\\u{61}\\u{62}\\u{63}");
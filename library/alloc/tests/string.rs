@@ -692,6 +692,17 @@ fn test_extend_ref() {
     assert_eq!(&a, "foobar");
 }
 
+#[test]
+fn test_extend_ref_str() {
+    let mut a = "foo".to_string();
+    a.extend(&["b", "a", "r"]);
+    assert_eq!(&a, "foobar");
+
+    let strings = vec!["ba".to_string(), String::new(), "z".to_string()];
+    a.extend(strings.iter());
+    assert_eq!(&a, "foobarbaz");
+}
+
 #[test]
 fn test_into_boxed_str() {
     let xs = String::from("hello my name is bob");
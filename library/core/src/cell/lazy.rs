@@ -125,6 +125,51 @@ pub fn force(this: &LazyCell<T, F>) -> &T {
         }
     }
 
+    /// Forces the evaluation of this lazy value and returns a mutable reference to
+    /// the result.
+    ///
+    /// Since this takes the cell by mutable reference, no other borrows of the value
+    /// can exist and it is safe to hand out a unique reference.
+    ///
+    /// The thread-safe [`std::sync::LazyLock`] has no such method. It is meant to be
+    /// shared between threads, usually as a `static`, so a mutable reference to it is
+    /// almost never available. A `LazyCell` is often owned by a single value instead,
+    /// which can hand out a mutable reference to it directly.
+    ///
+    /// [`std::sync::LazyLock`]: ../../std/sync/struct.LazyLock.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(lazy_cell)]
+    /// #![feature(lazy_cell_force_mut)]
+    ///
+    /// use std::cell::LazyCell;
+    ///
+    /// let mut lazy = LazyCell::new(|| 92);
+    ///
+    /// let p = LazyCell::force_mut(&mut lazy);
+    /// assert_eq!(*p, 92);
+    /// *p = 44;
+    /// assert_eq!(*lazy, 44);
+    /// ```
+    #[inline]
+    #[unstable(feature = "lazy_cell_force_mut", issue = "none")]
+    pub fn force_mut(this: &mut LazyCell<T, F>) -> &mut T {
+        let state = this.state.get_mut();
+        if let State::Uninit(_) = state {
+            // Mark the state as poisoned while the closure runs, so that the cell
+            // stays poisoned if the closure panics.
+            let State::Uninit(f) = mem::replace(state, State::Poisoned) else { unreachable!() };
+            *state = State::Init(f());
+        }
+        match state {
+            State::Init(data) => data,
+            State::Uninit(_) => unreachable!(),
+            State::Poisoned => panic!("LazyCell has previously been poisoned"),
+        }
+    }
+
     /// # Safety
     /// May only be called when the state is `Uninit`.
     #[cold]
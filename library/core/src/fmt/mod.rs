@@ -36,6 +36,31 @@ pub enum Alignment {
     Center,
 }
 
+impl Alignment {
+    /// Returns the character that selects this alignment in a format string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(fmt_alignment_as_char)]
+    /// use std::fmt::Alignment;
+    ///
+    /// assert_eq!(Alignment::Left.as_char(), '<');
+    /// assert_eq!(Alignment::Right.as_char(), '>');
+    /// assert_eq!(Alignment::Center.as_char(), '^');
+    /// ```
+    #[unstable(feature = "fmt_alignment_as_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn as_char(self) -> char {
+        match self {
+            Alignment::Left => '<',
+            Alignment::Right => '>',
+            Alignment::Center => '^',
+        }
+    }
+}
+
 #[stable(feature = "debug_builders", since = "1.2.0")]
 pub use self::builders::{DebugList, DebugMap, DebugSet, DebugStruct, DebugTuple};
 
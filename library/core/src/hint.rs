@@ -143,6 +143,7 @@
 #[track_caller]
 #[unstable(feature = "hint_assert_unchecked", issue = "119131")]
 #[rustc_const_unstable(feature = "const_hint_assert_unchecked", issue = "119131")]
+#[rustc_diagnostic_item = "hint_assert_unchecked"]
 pub const unsafe fn assert_unchecked(cond: bool) {
     // SAFETY: The caller promised `cond` is true.
     unsafe {
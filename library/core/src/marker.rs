@@ -184,6 +184,39 @@ pub trait Unsize<T: ?Sized> {
     // Empty.
 }
 
+/// Derive macro that allows a smart pointer to be coerced to point to an unsized type, the way
+/// `Box<T>` can be coerced to `Box<dyn Trait>` or `Box<[T]>`.
+///
+/// The type parameter for the pointed-to type has to be marked with `#[pointee]`. The macro then
+/// implements [`ops::CoerceUnsized`] and [`ops::DispatchFromDyn`] for the struct, so it can take
+/// part in unsizing coercions and be used as the receiver of a method called on a trait object.
+/// This only works for the same kind of structs that could implement those traits by hand.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(derive_smart_pointer)]
+/// use std::marker::SmartPointer;
+///
+/// #[derive(SmartPointer)]
+/// struct MyBox<#[pointee] T: ?Sized> {
+///     ptr: Box<T>,
+/// }
+///
+/// let array = MyBox { ptr: Box::new([1, 2, 3]) };
+/// let slice: MyBox<[i32]> = array;
+/// assert_eq!(slice.ptr.len(), 3);
+/// ```
+///
+/// [`ops::CoerceUnsized`]: crate::ops::CoerceUnsized
+/// [`ops::DispatchFromDyn`]: crate::ops::DispatchFromDyn
+#[rustc_builtin_macro(SmartPointer, attributes(pointee))]
+#[allow_internal_unstable(coerce_unsized, dispatch_from_dyn, unsize)]
+#[unstable(feature = "derive_smart_pointer", issue = "none")]
+pub macro SmartPointer($item:item) {
+    /* compiler built-in */
+}
+
 /// Required trait for constants used in pattern matches.
 ///
 /// Any type that derives `PartialEq` automatically implements this trait,
@@ -171,6 +171,41 @@ fn test_build_hasher_object_safe() {
     let _: &dyn BuildHasher<Hasher = DefaultHasher> = &RandomState::new();
 }
 
+#[test]
+fn test_build_hasher_hash_one() {
+    use std::hash::{BuildHasherDefault, RandomState};
+
+    fn check<S: BuildHasher, T: Hash + Copy>(state: &S, value: T) {
+        let mut hasher = state.build_hasher();
+        value.hash(&mut hasher);
+        assert_eq!(state.hash_one(value), hasher.finish());
+    }
+
+    fn check_all<S: BuildHasher>(state: &S) {
+        check(state, 0u8);
+        check(state, u64::MAX);
+        check(state, -1i32);
+        check(state, 'x');
+        check(state, true);
+        check(state, ());
+        check(state, "");
+        check(state, "hello");
+        check(state, &[1u16, 2, 3][..]);
+        check(state, (1u8, "two", [3u32; 3]));
+        check(state, Some(&Custom { hash: 7 }));
+    }
+
+    check_all(&RandomState::new());
+    check_all(&BuildHasherDefault::<MyHasher>::default());
+    check(&BuildHasherDefault::<CustomHasher>::default(), &Custom { hash: 7 });
+
+    // Hashing by value and by reference gives the same result.
+    let state = RandomState::new();
+    let v = vec![1, 2, 3];
+    assert_eq!(state.hash_one(&v), state.hash_one(&v[..]));
+    assert_eq!(state.hash_one(&v), state.hash_one(v));
+}
+
 // just tests by whether or not this compiles
 fn _build_hasher_default_impl_all_auto_traits<T>() {
     use std::panic::{RefUnwindSafe, UnwindSafe};
@@ -108,6 +108,24 @@ fn lazy_new() {
     assert_eq!(called.get(), 1);
 }
 
+#[test]
+fn lazy_force_mut() {
+    let called = Cell::new(0);
+    let mut x = LazyCell::new(|| {
+        called.set(called.get() + 1);
+        92
+    });
+
+    let p = LazyCell::force_mut(&mut x);
+    assert_eq!(*p, 92);
+    *p = 44;
+    assert_eq!(called.get(), 1);
+
+    assert_eq!(*LazyCell::force_mut(&mut x), 44);
+    assert_eq!(*x, 44);
+    assert_eq!(called.get(), 1);
+}
+
 // Check that we can infer `T` from closure's type.
 #[test]
 fn lazy_type_inference() {
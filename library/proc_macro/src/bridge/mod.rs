@@ -8,7 +8,7 @@
 
 #![deny(unsafe_code)]
 
-use crate::{Delimiter, Level, Spacing};
+use crate::{Applicability, Delimiter, Level, Spacing};
 use std::fmt;
 use std::hash::Hash;
 use std::marker;
@@ -300,6 +300,7 @@ fn unmark(self) -> Self::Unmarked {
     Delimiter,
     LitKind,
     Level,
+    Applicability,
     Spacing,
 }
 
@@ -319,6 +320,14 @@ enum Level {
         Help,
     }
 );
+rpc_encode_decode!(
+    enum Applicability {
+        MachineApplicable,
+        MaybeIncorrect,
+        HasPlaceholders,
+        Unspecified,
+    }
+);
 rpc_encode_decode!(
     enum Spacing {
         Alone,
@@ -500,16 +509,29 @@ enum TokenTree<TokenStream, Span, Symbol> {
     }
 );
 
+#[derive(Clone, Debug)]
+pub struct Suggestion<Span> {
+    pub span: Span,
+    pub message: String,
+    pub replacement: String,
+    pub applicability: Applicability,
+}
+
+compound_traits!(
+    struct Suggestion<Span> { span, message, replacement, applicability }
+);
+
 #[derive(Clone, Debug)]
 pub struct Diagnostic<Span> {
     pub level: Level,
     pub message: String,
     pub spans: Vec<Span>,
     pub children: Vec<Diagnostic<Span>>,
+    pub suggestions: Vec<Suggestion<Span>>,
 }
 
 compound_traits!(
-    struct Diagnostic<Span> { level, message, spans, children }
+    struct Diagnostic<Span> { level, message, spans, children, suggestions }
 );
 
 /// Globals provided alongside the initial inputs for a macro expansion.
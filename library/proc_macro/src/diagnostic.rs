@@ -15,6 +15,27 @@ pub enum Level {
     Help,
 }
 
+/// Indicates how confident the emitter of a suggestion is that applying it
+/// yields the intended code.
+///
+/// Tools such as `rustfix` and IDEs only apply suggestions automatically when
+/// they are [`MachineApplicable`](Applicability::MachineApplicable).
+#[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Applicability {
+    /// The suggestion is definitely what the user intended and can be applied
+    /// automatically.
+    MachineApplicable,
+    /// The suggestion may be what the user intended, but it is uncertain.
+    MaybeIncorrect,
+    /// The suggestion contains placeholders like `(...)` or `{ /* fields */ }`
+    /// that the user has to fill in.
+    HasPlaceholders,
+    /// The applicability of the suggestion is unknown.
+    Unspecified,
+}
+
 /// Trait implemented by types that can be converted into a set of `Span`s.
 #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
 pub trait MultiSpan {
@@ -52,6 +73,16 @@ pub struct Diagnostic {
     message: String,
     spans: Vec<Span>,
     children: Vec<Diagnostic>,
+    suggestions: Vec<Suggestion>,
+}
+
+/// A suggested replacement for the source code covered by a span.
+#[derive(Clone, Debug)]
+struct Suggestion {
+    span: Span,
+    message: String,
+    replacement: String,
+    applicability: Applicability,
 }
 
 macro_rules! diagnostic_child_methods {
@@ -97,7 +128,13 @@ impl Diagnostic {
     /// Creates a new diagnostic with the given `level` and `message`.
     #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
     pub fn new<T: Into<String>>(level: Level, message: T) -> Diagnostic {
-        Diagnostic { level, message: message.into(), spans: vec![], children: vec![] }
+        Diagnostic {
+            level,
+            message: message.into(),
+            spans: vec![],
+            children: vec![],
+            suggestions: vec![],
+        }
     }
 
     /// Creates a new diagnostic with the given `level` and `message` pointing to
@@ -108,7 +145,13 @@ pub fn spanned<S, T>(spans: S, level: Level, message: T) -> Diagnostic
         S: MultiSpan,
         T: Into<String>,
     {
-        Diagnostic { level, message: message.into(), spans: spans.into_spans(), children: vec![] }
+        Diagnostic {
+            level,
+            message: message.into(),
+            spans: spans.into_spans(),
+            children: vec![],
+            suggestions: vec![],
+        }
     }
 
     diagnostic_child_methods!(span_error, error, Level::Error);
@@ -116,6 +159,32 @@ pub fn spanned<S, T>(spans: S, level: Level, message: T) -> Diagnostic
     diagnostic_child_methods!(span_note, note, Level::Note);
     diagnostic_child_methods!(span_help, help, Level::Help);
 
+    /// Adds a suggestion to `self` to replace the source code covered by `span`
+    /// with `replacement`, described by `message`.
+    ///
+    /// The `applicability` tells tools whether the suggestion can be applied
+    /// automatically.
+    #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
+    pub fn span_suggestion<T, U>(
+        mut self,
+        span: Span,
+        message: T,
+        replacement: U,
+        applicability: Applicability,
+    ) -> Diagnostic
+    where
+        T: Into<String>,
+        U: Into<String>,
+    {
+        self.suggestions.push(Suggestion {
+            span,
+            message: message.into(),
+            replacement: replacement.into(),
+            applicability,
+        });
+        self
+    }
+
     /// Returns the diagnostic `level` for `self`.
     #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
     pub fn level(&self) -> Level {
@@ -167,6 +236,16 @@ fn to_internal(diag: Diagnostic) -> crate::bridge::Diagnostic<crate::bridge::cli
                 message: diag.message,
                 spans: diag.spans.into_iter().map(|s| s.0).collect(),
                 children: diag.children.into_iter().map(to_internal).collect(),
+                suggestions: diag
+                    .suggestions
+                    .into_iter()
+                    .map(|s| crate::bridge::Suggestion {
+                        span: s.span.0,
+                        message: s.message,
+                        replacement: s.replacement,
+                        applicability: s.applicability,
+                    })
+                    .collect(),
             }
         }
 
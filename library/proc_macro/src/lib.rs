@@ -43,7 +43,7 @@
 mod diagnostic;
 
 #[unstable(feature = "proc_macro_diagnostic", issue = "54140")]
-pub use diagnostic::{Diagnostic, Level, MultiSpan};
+pub use diagnostic::{Applicability, Diagnostic, Level, MultiSpan};
 
 use std::ops::{Range, RangeBounds};
 use std::path::PathBuf;
@@ -93,6 +93,7 @@
 use crate::ffi::c_void;
 use crate::fmt;
 use crate::panic::UnwindSafe;
+use crate::path::PathBuf;
 use crate::sync::atomic::{AtomicUsize, Ordering::Relaxed};
 use crate::sync::LazyLock;
 use crate::sys_common::backtrace::{lock, output_filename, set_image_base};
@@ -159,7 +160,9 @@ enum RawFrame {
     Fake,
 }
 
-struct BacktraceSymbol {
+/// A symbol that a [`BacktraceFrame`] was resolved to.
+#[unstable(feature = "backtrace_symbols", issue = "none")]
+pub struct BacktraceSymbol {
     name: Option<Vec<u8>>,
     filename: Option<BytesOrWide>,
     lineno: Option<u32>,
@@ -207,6 +210,40 @@ fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+#[unstable(feature = "backtrace_symbols", issue = "none")]
+impl fmt::Display for BacktraceFrame {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.symbols.is_empty() {
+            return write!(fmt, "{:?}", self.frame.ip());
+        }
+
+        for (i, symbol) in self.symbols.iter().enumerate() {
+            if i > 0 {
+                writeln!(fmt)?;
+            }
+
+            if let Some(fn_name) = symbol.name.as_ref().map(|b| backtrace_rs::SymbolName::new(b)) {
+                write!(fmt, "{:#}", fn_name)?;
+            } else {
+                write!(fmt, "<unknown>")?;
+            }
+
+            if let Some(fname) = symbol.filename.as_ref() {
+                write!(fmt, "\n    at {:?}", fname)?;
+                if let Some(line) = symbol.lineno {
+                    write!(fmt, ":{line}")?;
+                    if let Some(col) = symbol.colno {
+                        write!(fmt, ":{col}")?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[unstable(feature = "backtrace_symbols", issue = "none")]
 impl fmt::Debug for BacktraceSymbol {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
         // FIXME: improve formatting: https://github.com/rust-lang/rust/issues/65280
@@ -378,6 +415,68 @@ pub fn frames(&'a self) -> &'a [BacktraceFrame] {
     }
 }
 
+impl BacktraceFrame {
+    /// Returns the symbols this frame was resolved to.
+    ///
+    /// A single frame resolves to several symbols when functions were inlined
+    /// into each other, in which case the innermost function comes first. The
+    /// slice is empty if no symbol information is available for this frame.
+    #[must_use]
+    #[unstable(feature = "backtrace_symbols", issue = "none")]
+    pub fn symbols(&self) -> &[BacktraceSymbol] {
+        &self.symbols
+    }
+}
+
+impl BacktraceSymbol {
+    /// Returns the demangled name of this symbol, without the trailing hash,
+    /// if it is known.
+    #[must_use]
+    #[unstable(feature = "backtrace_symbols", issue = "none")]
+    pub fn name(&self) -> Option<String> {
+        self.name.as_ref().map(|b| format!("{:#}", backtrace_rs::SymbolName::new(b)))
+    }
+
+    /// Returns the path of the source file this symbol is defined in, if it is
+    /// known.
+    #[must_use]
+    #[unstable(feature = "backtrace_symbols", issue = "none")]
+    pub fn filename(&self) -> Option<PathBuf> {
+        match self.filename.as_ref()? {
+            #[cfg(unix)]
+            BytesOrWide::Bytes(bytes) => {
+                use crate::os::unix::prelude::*;
+                Some(PathBuf::from(crate::ffi::OsStr::from_bytes(bytes)))
+            }
+            #[cfg(not(unix))]
+            BytesOrWide::Bytes(bytes) => crate::str::from_utf8(bytes).ok().map(PathBuf::from),
+            #[cfg(windows)]
+            BytesOrWide::Wide(wide) => {
+                use crate::os::windows::prelude::*;
+                Some(crate::ffi::OsString::from_wide(wide).into())
+            }
+            #[cfg(not(windows))]
+            BytesOrWide::Wide(_wide) => None,
+        }
+    }
+
+    /// Returns the line number of this symbol in its source file, if it is
+    /// known.
+    #[must_use]
+    #[unstable(feature = "backtrace_symbols", issue = "none")]
+    pub fn lineno(&self) -> Option<u32> {
+        self.lineno
+    }
+
+    /// Returns the column number of this symbol in its source file, if it is
+    /// known.
+    #[must_use]
+    #[unstable(feature = "backtrace_symbols", issue = "none")]
+    pub fn colno(&self) -> Option<u32> {
+        self.colno
+    }
+}
+
 #[stable(feature = "backtrace", since = "1.65.0")]
 impl fmt::Display for Backtrace {
     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
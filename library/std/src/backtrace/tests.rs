@@ -93,6 +93,41 @@ fn test_frames() {
     assert!(iter.all(|(f, e)| format!("{f:#?}") == *e));
 }
 
+#[test]
+fn test_frame_symbols() {
+    let frames = generate_fake_frames();
+
+    let symbols = frames[0].symbols();
+    assert_eq!(symbols.len(), 1);
+    assert_eq!(symbols[0].name().as_deref(), Some("std::backtrace::Backtrace::create"));
+    assert_eq!(symbols[0].filename(), Some(PathBuf::from("rust/backtrace.rs")));
+    assert_eq!(symbols[0].lineno(), Some(100));
+    assert_eq!(symbols[0].colno(), None);
+
+    let symbols = frames[1].symbols();
+    assert_eq!(symbols[0].name().as_deref(), Some("__rust_maybe_catch_panic"));
+    assert_eq!(symbols[0].filename(), None);
+    assert_eq!(symbols[0].lineno(), None);
+
+    let names: Vec<_> = frames[2].symbols().iter().map(|s| s.name().unwrap()).collect();
+    assert_eq!(names, ["std::rt::lang_start_internal", "std::rt::lang_start"]);
+    assert_eq!(frames[2].symbols()[0].colno(), Some(5));
+}
+
+#[test]
+fn test_frame_display() {
+    let frames = generate_fake_frames();
+
+    assert_eq!(frames[1].to_string(), "__rust_maybe_catch_panic");
+
+    #[rustfmt::skip]
+    let expected = "std::rt::lang_start_internal\
+    \n    at rust/rt.rs:300:5\
+    \nstd::rt::lang_start\
+    \n    at rust/rt.rs:400";
+    assert_eq!(frames[2].to_string(), expected);
+}
+
 #[test]
 fn backtrace_unwind_safe() {
     fn assert_unwind_safe<T: UnwindSafe + RefUnwindSafe>() {}
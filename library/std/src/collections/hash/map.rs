@@ -2588,6 +2588,38 @@ pub fn or_insert_with_key<F: FnOnce(&K) -> V>(self, default: F) -> &'a mut V {
         }
     }
 
+    /// Ensures a value is in the entry by inserting the result of the fallible default function
+    /// if empty, and returns a mutable reference to the value in the entry.
+    ///
+    /// If the default function returns an error, the entry is left empty and the error is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(entry_or_try_insert_with)]
+    /// use std::collections::HashMap;
+    ///
+    /// let mut map: HashMap<&str, u32> = HashMap::new();
+    ///
+    /// assert!(map.entry("poneyland").or_try_insert_with(|| "twelve".parse()).is_err());
+    /// assert!(!map.contains_key("poneyland"));
+    ///
+    /// assert_eq!(map.entry("poneyland").or_try_insert_with(|| "12".parse()), Ok(&mut 12));
+    /// assert_eq!(map["poneyland"], 12);
+    /// ```
+    #[inline]
+    #[unstable(feature = "entry_or_try_insert_with", issue = "none")]
+    pub fn or_try_insert_with<E, F: FnOnce() -> Result<V, E>>(
+        self,
+        default: F,
+    ) -> Result<&'a mut V, E> {
+        match self {
+            Occupied(entry) => Ok(entry.into_mut()),
+            Vacant(entry) => Ok(entry.insert(default()?)),
+        }
+    }
+
     /// Returns a reference to this entry's key.
     ///
     /// # Examples
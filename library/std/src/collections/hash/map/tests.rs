@@ -700,6 +700,27 @@ fn test_entry() {
     assert_eq!(map.len(), 6);
 }
 
+#[test]
+fn test_entry_or_try_insert_with() {
+    let mut map = HashMap::from([(1, 10)]);
+
+    // Existing key: the default function is not called
+    assert_eq!(
+        map.entry(1).or_try_insert_with(|| -> Result<_, ()> { unreachable!() }),
+        Ok(&mut 10)
+    );
+
+    // Inexistent key, failing default function: the entry stays empty
+    assert_eq!(map.entry(2).or_try_insert_with(|| Err("nope")), Err("nope"));
+    assert_eq!(map.get(&2), None);
+    assert_eq!(map.len(), 1);
+
+    // Inexistent key, succeeding default function
+    assert_eq!(map.entry(2).or_try_insert_with(|| Ok::<_, ()>(20)), Ok(&mut 20));
+    assert_eq!(map.get(&2), Some(&20));
+    assert_eq!(map.len(), 2);
+}
+
 #[test]
 fn test_entry_take_doesnt_corrupt() {
     #![allow(deprecated)] //rand
@@ -944,6 +965,64 @@ fn test_raw_entry() {
     }
 }
 
+#[test]
+fn test_raw_entry_colliding_hasher() {
+    use super::RawEntryMut::{Occupied, Vacant};
+    use crate::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+    // A hasher that only produces four distinct hashes, so that lookups by hash have to fall
+    // back to the equality predicate to tell keys apart.
+    #[derive(Default)]
+    struct CollidingHasher(u64);
+
+    impl Hasher for CollidingHasher {
+        fn finish(&self) -> u64 {
+            self.0 % 4
+        }
+
+        fn write(&mut self, bytes: &[u8]) {
+            for &b in bytes {
+                self.0 = self.0.wrapping_mul(31).wrapping_add(b as u64);
+            }
+        }
+    }
+
+    let mut map: HashMap<i32, i32, BuildHasherDefault<CollidingHasher>> = HashMap::default();
+
+    for k in 0..32 {
+        let hash = map.hasher().hash_one(k);
+        match map.raw_entry_mut().from_hash(hash, |q| *q == k) {
+            Occupied(_) => unreachable!(),
+            Vacant(view) => {
+                let (key, value) = view.insert_hashed_nocheck(hash, k, k * 10);
+                assert_eq!((*key, *value), (k, k * 10));
+            }
+        }
+    }
+    assert_eq!(map.len(), 32);
+
+    for k in 0..32 {
+        let hash = map.hasher().hash_one(k);
+        assert_eq!(map.raw_entry().from_key(&k), Some((&k, &(k * 10))));
+        assert_eq!(map.raw_entry().from_hash(hash, |q| *q == k), Some((&k, &(k * 10))));
+        assert_eq!(map.raw_entry().from_key_hashed_nocheck(hash, &k), Some((&k, &(k * 10))));
+        // A matching hash alone is not enough to find an entry.
+        assert_eq!(map.raw_entry().from_hash(hash, |_| false), None);
+    }
+
+    // Removing one key leaves the keys that share its hash in place.
+    let hash = map.hasher().hash_one(5);
+    match map.raw_entry_mut().from_key_hashed_nocheck(hash, &5) {
+        Vacant(_) => unreachable!(),
+        Occupied(view) => assert_eq!(view.remove_entry(), (5, 50)),
+    }
+    assert_eq!(map.raw_entry().from_key(&5), None);
+    assert_eq!(map.len(), 31);
+    for k in (0..32).filter(|&k| k != 5) {
+        assert_eq!(map.raw_entry().from_key(&k), Some((&k, &(k * 10))));
+    }
+}
+
 mod test_extract_if {
     use super::*;
 
@@ -518,6 +518,18 @@ fn file_create_new_already_exists_error() {
     check!(fs::File::create(file));
     let e = fs::OpenOptions::new().write(true).create_new(true).open(file).unwrap_err();
     assert_eq!(e.kind(), ErrorKind::AlreadyExists);
+    let e = fs::File::create_new(file).unwrap_err();
+    assert_eq!(e.kind(), ErrorKind::AlreadyExists);
+}
+
+#[test]
+fn file_create_new_creates_file() {
+    let tmpdir = tmpdir();
+    let file = &tmpdir.join("file_create_new_creates_file");
+    let mut f = check!(fs::File::create_new(file));
+    check!(f.write_all(b"hello"));
+    drop(f);
+    assert_eq!(check!(fs::read(file)), b"hello");
 }
 
 #[test]
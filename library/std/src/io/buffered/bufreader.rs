@@ -221,6 +221,41 @@ pub fn into_inner(self) -> R
         self.inner
     }
 
+    /// Disassembles this `BufReader<R>`, returning the underlying reader and the data that was
+    /// buffered but not yet consumed.
+    ///
+    /// Unlike [`into_inner`], no data is lost: reading the returned bytes and then reading from
+    /// the returned reader yields the same data as continuing to read from the `BufReader`.
+    ///
+    /// [`into_inner`]: BufReader::into_inner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(bufreader_into_parts)]
+    /// use std::io::{BufRead, BufReader, Read};
+    ///
+    /// let data: &[u8] = b"hello world";
+    /// let mut reader = BufReader::with_capacity(8, data);
+    /// assert_eq!(reader.fill_buf().unwrap(), b"hello wo");
+    /// reader.consume(6);
+    ///
+    /// let (mut inner, buffered) = reader.into_parts();
+    /// assert_eq!(buffered, b"wo");
+    ///
+    /// let mut rest = Vec::new();
+    /// inner.read_to_end(&mut rest).unwrap();
+    /// assert_eq!(rest, b"rld");
+    /// ```
+    #[unstable(feature = "bufreader_into_parts", issue = "none")]
+    pub fn into_parts(self) -> (R, Vec<u8>)
+    where
+        R: Sized,
+    {
+        let buf = self.buf.buffer().to_vec();
+        (self.inner, buf)
+    }
+
     /// Invalidates all data in the internal buffer.
     #[inline]
     pub(in crate::io) fn discard_buffer(&mut self) {
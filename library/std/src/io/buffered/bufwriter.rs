@@ -663,6 +663,19 @@ fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
         self.flush_buf()?;
         self.get_mut().seek(pos)
     }
+
+    /// Returns the current seek position from the start of the stream.
+    ///
+    /// Unlike [`seek`](BufWriter::seek), this does not write out the internal
+    /// buffer. The position is that of the underlying writer plus the number
+    /// of bytes still held in the buffer.
+    fn stream_position(&mut self) -> io::Result<u64> {
+        let buffered = self.buf.len() as u64;
+        self.get_mut().stream_position().map(|pos| {
+            pos.checked_add(buffered)
+                .expect("overflow when adding buffer size to inner stream position")
+        })
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
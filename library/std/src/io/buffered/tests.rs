@@ -214,6 +214,26 @@ fn test_buffered_reader_invalidated_after_seek() {
     assert_eq!(buffer, [3, 4]);
 }
 
+#[test]
+fn test_buffered_reader_into_parts() {
+    let inner: &[u8] = &[5, 6, 7, 0, 1, 2, 3, 4];
+    let mut reader = BufReader::with_capacity(3, io::Cursor::new(inner));
+
+    // Nothing buffered yet.
+    let (cursor, buffered) = BufReader::with_capacity(3, io::Cursor::new(inner)).into_parts();
+    assert_eq!(cursor.position(), 0);
+    assert!(buffered.is_empty());
+
+    assert_eq!(reader.fill_buf().ok(), Some(&[5, 6, 7][..]));
+    reader.consume(1);
+
+    let (mut cursor, buffered) = reader.into_parts();
+    assert_eq!(buffered, [6, 7]);
+    let mut rest = Vec::new();
+    assert_eq!(cursor.read_to_end(&mut rest).ok(), Some(5));
+    assert_eq!(rest, [0, 1, 2, 3, 4]);
+}
+
 #[test]
 fn test_buffered_reader_seek_underflow() {
     // gimmick reader that yields its position modulo 256 for each byte
@@ -381,6 +401,21 @@ fn test_buffered_writer_seek() {
     assert_eq!(&w.into_inner().unwrap().into_inner()[..], &[0, 1, 8, 9, 4, 5, 6, 7]);
 }
 
+#[test]
+fn test_buffered_writer_stream_position() {
+    let mut w = BufWriter::with_capacity(4, io::Cursor::new(Vec::new()));
+    w.write_all(&[0, 1]).unwrap();
+    assert_eq!(w.stream_position().ok(), Some(2));
+    // The buffered bytes have not been written out.
+    assert_eq!(w.get_ref().position(), 0);
+    w.write_all(&[2, 3, 4, 5, 6]).unwrap();
+    assert_eq!(w.stream_position().ok(), Some(7));
+    w.seek(SeekFrom::Start(1)).unwrap();
+    w.write_all(&[7]).unwrap();
+    assert_eq!(w.stream_position().ok(), Some(2));
+    assert_eq!(&w.into_inner().unwrap().into_inner()[..], &[0, 7, 2, 3, 4, 5, 6]);
+}
+
 #[test]
 fn test_read_until() {
     let inner: &[u8] = &[0, 1, 2, 1, 0];
@@ -72,6 +72,74 @@ pub fn copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
     }
 }
 
+/// Copies exactly `n` bytes from a reader into a writer.
+///
+/// This behaves like [`copy`], except that it stops after `n` bytes have
+/// been transferred instead of continuing until `reader` returns EOF. Bytes
+/// beyond the first `n` are left unread in `reader`.
+///
+/// On success, `n` is returned.
+///
+/// # Errors
+///
+/// If `reader` reaches EOF before `n` bytes have been copied, an error of
+/// the kind [`ErrorKind::UnexpectedEof`] is returned. The bytes read up to
+/// that point have already been written to `writer`.
+///
+/// Any other error from [`read`] or [`write`] is returned immediately, and
+/// [`ErrorKind::Interrupted`] is handled like in [`copy`].
+///
+/// [`read`]: Read::read
+/// [`write`]: Write::write
+/// [`ErrorKind::UnexpectedEof`]: crate::io::ErrorKind::UnexpectedEof
+/// [`ErrorKind::Interrupted`]: crate::io::ErrorKind::Interrupted
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_copy_n)]
+/// use std::io;
+///
+/// fn main() -> io::Result<()> {
+///     let mut reader: &[u8] = b"hello world";
+///     let mut writer: Vec<u8> = vec![];
+///
+///     assert_eq!(io::copy_n(&mut reader, &mut writer, 5)?, 5);
+///     assert_eq!(&b"hello"[..], &writer[..]);
+///     assert_eq!(&b" world"[..], reader);
+///
+///     let err = io::copy_n(&mut reader, &mut writer, 10).unwrap_err();
+///     assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+///     Ok(())
+/// }
+/// ```
+///
+/// # Platform-specific behavior
+///
+/// This uses the same copy offloading as [`copy`], so on Linux (including
+/// Android) data may be moved directly between file descriptors.
+///
+/// Note that platform-specific behavior [may change in the future][changes].
+///
+/// [changes]: crate::io#platform-specific-behavior
+#[unstable(feature = "io_copy_n", issue = "none")]
+pub fn copy_n<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W, n: u64) -> Result<u64>
+where
+    R: Read,
+    W: Write,
+{
+    // `Take` is understood by the kernel copy specializations, so limiting
+    // the reader this way keeps the zero-copy paths available.
+    let copied = copy(&mut reader.take(n), writer)?;
+    if copied < n {
+        return Err(super::const_io_error!(
+            super::ErrorKind::UnexpectedEof,
+            "failed to copy the requested number of bytes"
+        ));
+    }
+    Ok(copied)
+}
+
 /// The userspace read-write-loop implementation of `io::copy` that is used when
 /// OS-specific specializations for copy offloading are not available or not applicable.
 pub(crate) fn generic_copy<R: ?Sized, W: ?Sized>(reader: &mut R, writer: &mut W) -> Result<u64>
@@ -13,6 +13,33 @@ fn copy_copies() {
     assert_eq!(copy(&mut r as &mut dyn Read, &mut w as &mut dyn Write).unwrap(), 1 << 17);
 }
 
+#[test]
+fn copy_n_copies_exactly_n() {
+    let mut r: &[u8] = b"hello world";
+    let mut w = Vec::new();
+    assert_eq!(copy_n(&mut r, &mut w, 5).unwrap(), 5);
+    assert_eq!(w, b"hello");
+    assert_eq!(r, b" world");
+
+    assert_eq!(copy_n(&mut r, &mut w, 0).unwrap(), 0);
+    assert_eq!(w, b"hello");
+
+    let mut r = repeat(0).take(1 << 17);
+    let mut w = sink();
+    assert_eq!(copy_n(&mut r, &mut w, 1 << 16).unwrap(), 1 << 16);
+    assert_eq!(r.limit(), 1 << 16);
+}
+
+#[test]
+fn copy_n_unexpected_eof() {
+    let mut r: &[u8] = b"hello";
+    let mut w = Vec::new();
+    let err = copy_n(&mut r, &mut w, 6).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    assert_eq!(w, b"hello");
+    assert!(r.is_empty());
+}
+
 struct ShortReader {
     cap: usize,
     read_size: usize,
@@ -208,6 +208,31 @@ impl<T> Cursor<T>
 where
     T: AsRef<[u8]>,
 {
+    /// Returns the number of bytes remaining after the current position.
+    ///
+    /// If the position is past the end of the inner buffer, this returns 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(cursor_remaining)]
+    /// use std::io::Cursor;
+    ///
+    /// let mut buff = Cursor::new(vec![1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(buff.remaining(), 5);
+    ///
+    /// buff.set_position(2);
+    /// assert_eq!(buff.remaining(), 3);
+    ///
+    /// buff.set_position(10);
+    /// assert_eq!(buff.remaining(), 0);
+    /// ```
+    #[unstable(feature = "cursor_remaining", issue = "86369")]
+    pub fn remaining(&self) -> u64 {
+        (self.inner.as_ref().len() as u64).saturating_sub(self.pos)
+    }
+
     /// Returns the remaining slice.
     ///
     /// # Examples
@@ -260,6 +285,35 @@ pub fn is_empty(&self) -> bool {
     }
 }
 
+impl<T> Cursor<T>
+where
+    T: AsMut<[u8]>,
+{
+    /// Returns the remaining slice as a mutable slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(cursor_remaining)]
+    /// use std::io::Cursor;
+    ///
+    /// let mut buff = Cursor::new(vec![1, 2, 3, 4, 5]);
+    ///
+    /// buff.set_position(3);
+    /// buff.remaining_slice_mut()[0] = 0;
+    /// assert_eq!(buff.get_ref(), &[1, 2, 3, 0, 5]);
+    ///
+    /// buff.set_position(6);
+    /// assert_eq!(buff.remaining_slice_mut(), &mut []);
+    /// ```
+    #[unstable(feature = "cursor_remaining", issue = "86369")]
+    pub fn remaining_slice_mut(&mut self) -> &mut [u8] {
+        let inner = self.inner.as_mut();
+        let start = self.pos.min(inner.len() as u64);
+        &mut inner[(start as usize)..]
+    }
+}
+
 #[stable(feature = "rust1", since = "1.0.0")]
 impl<T> Clone for Cursor<T>
 where
@@ -511,6 +511,33 @@ fn vec_seek_and_write_past_usize_max() {
     assert!(c.write_all(&[1, 2, 3]).is_err());
 }
 
+#[test]
+fn test_remaining() {
+    let mut c = Cursor::new([1u8, 2, 3, 4]);
+    assert_eq!(c.remaining(), 4);
+    assert_eq!(c.remaining_slice(), &[1, 2, 3, 4]);
+
+    c.set_position(3);
+    assert_eq!(c.remaining(), 1);
+    c.remaining_slice_mut()[0] = 9;
+    assert_eq!(c.remaining_slice(), &[9]);
+
+    c.set_position(4);
+    assert_eq!(c.remaining(), 0);
+    assert!(c.remaining_slice_mut().is_empty());
+
+    c.set_position(u64::MAX);
+    assert_eq!(c.remaining(), 0);
+    assert!(c.remaining_slice().is_empty());
+    assert!(c.remaining_slice_mut().is_empty());
+
+    let mut buf = [1u8, 2, 3];
+    let mut c = Cursor::new(&mut buf[..]);
+    c.set_position(1);
+    c.remaining_slice_mut().copy_from_slice(&[7, 8]);
+    assert_eq!(buf, [1, 7, 8]);
+}
+
 #[test]
 fn test_partial_eq() {
     assert_eq!(Cursor::new(Vec::<u8>::new()), Cursor::new(Vec::<u8>::new()));
@@ -331,6 +331,12 @@
 
 #[unstable(feature = "read_buf", issue = "78485")]
 pub use core::io::{BorrowedBuf, BorrowedCursor};
+#[unstable(feature = "seek_sink", issue = "none")]
+pub use self::util::SeekSink;
+#[unstable(feature = "io_chain_n", issue = "none")]
+pub use self::util::{chain_n, ChainN};
+#[unstable(feature = "io_copy_n", issue = "none")]
+pub use self::copy::copy_n;
 pub(crate) use error::const_io_error;
 
 mod buffered;
@@ -965,6 +971,83 @@ fn read_buf_exact(&mut self, mut cursor: BorrowedCursor<'_>) -> Result<()> {
         Ok(())
     }
 
+    /// Read the exact number of bytes required to fill all of `bufs`.
+    ///
+    /// This method will continuously call [`read_vectored`] until all buffers
+    /// have been filled, like [`read_exact`] does for a single buffer. The
+    /// buffers are filled in order.
+    ///
+    /// # Errors
+    ///
+    /// If this function encounters an error of the kind
+    /// [`ErrorKind::Interrupted`] then the error is ignored and the operation
+    /// will continue.
+    ///
+    /// If this function encounters an "end of file" before completely filling
+    /// the buffers, it returns an error of the kind [`ErrorKind::UnexpectedEof`].
+    /// The contents of the buffers are unspecified in this case.
+    ///
+    /// If any other read error is encountered then this function immediately
+    /// returns. The contents of the buffers are unspecified in this case.
+    ///
+    /// # Notes
+    ///
+    /// Unlike [`read_vectored`], this takes a *mutable* reference to a slice of
+    /// [`IoSliceMut`]s, because it needs to modify the slice to keep track of
+    /// the bytes already read.
+    ///
+    /// Once this function returns, the contents of `bufs` are unspecified, as
+    /// this depends on how many calls to [`read_vectored`] were necessary. It
+    /// is best to understand this function as taking ownership of `bufs` and to
+    /// not use `bufs` afterwards. The underlying buffers, to which the
+    /// [`IoSliceMut`]s point (but not the [`IoSliceMut`]s themselves), hold the
+    /// read data.
+    ///
+    /// [`read_vectored`]: Read::read_vectored
+    /// [`read_exact`]: Read::read_exact
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(read_exact_vectored)]
+    /// # fn main() -> std::io::Result<()> {
+    ///
+    /// use std::io::{IoSliceMut, Read};
+    ///
+    /// let mut reader: &[u8] = &[1, 2, 3, 4, 5, 6];
+    /// let mut header = [0; 2];
+    /// let mut body = [0; 3];
+    ///
+    /// let mut bufs = [IoSliceMut::new(&mut header), IoSliceMut::new(&mut body)];
+    /// reader.read_exact_vectored(&mut bufs)?;
+    /// // Note: the contents of `bufs` is now undefined, see the Notes section.
+    ///
+    /// assert_eq!(header, [1, 2]);
+    /// assert_eq!(body, [3, 4, 5]);
+    /// assert_eq!(reader, [6]);
+    /// # Ok(()) }
+    /// ```
+    #[unstable(feature = "read_exact_vectored", issue = "none")]
+    fn read_exact_vectored(&mut self, mut bufs: &mut [IoSliceMut<'_>]) -> Result<()> {
+        // Guarantee that bufs is empty if it contains no space,
+        // to avoid calling read_vectored if there is nothing to fill.
+        IoSliceMut::advance_slices(&mut bufs, 0);
+        while !bufs.is_empty() {
+            match self.read_vectored(bufs) {
+                Ok(0) => {
+                    return Err(error::const_io_error!(
+                        ErrorKind::UnexpectedEof,
+                        "failed to fill whole buffer",
+                    ));
+                }
+                Ok(n) => IoSliceMut::advance_slices(&mut bufs, n),
+                Err(ref e) if e.is_interrupted() => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
     /// Creates a "by reference" adaptor for this instance of `Read`.
     ///
     /// The returned adapter also implements `Read` and will simply borrow this
@@ -1179,6 +1262,42 @@ pub fn read_to_string<R: Read>(mut reader: R) -> Result<String> {
     Ok(buf)
 }
 
+/// Calls `f` until it returns something other than an error of kind
+/// [`ErrorKind::Interrupted`].
+///
+/// Interrupted errors usually mean that a signal arrived while a blocking
+/// operation was in progress, and that the operation should simply be retried.
+/// This function wraps the usual retry loop and returns the first result that
+/// is not such an error.
+///
+/// # Examples
+///
+/// ```no_run
+/// #![feature(io_retry_interrupted)]
+/// use std::fs::File;
+/// use std::io::{self, Read};
+///
+/// fn main() -> io::Result<()> {
+///     let mut f = File::open("foo.txt")?;
+///     let mut buf = [0; 10];
+///     let n = io::retry_interrupted(|| f.read(&mut buf))?;
+///     println!("The bytes: {:?}", &buf[..n]);
+///     Ok(())
+/// }
+/// ```
+#[unstable(feature = "io_retry_interrupted", issue = "none")]
+pub fn retry_interrupted<T, F>(mut f: F) -> Result<T>
+where
+    F: FnMut() -> Result<T>,
+{
+    loop {
+        match f() {
+            Err(ref e) if e.is_interrupted() => {}
+            result => return result,
+        }
+    }
+}
+
 /// A buffer type used with `Read::read_vectored`.
 ///
 /// It is semantically a wrapper around an `&mut [u8]`, but is guaranteed to be
@@ -165,6 +165,24 @@ fn read_exact() {
     assert_eq!(c.read_exact(&mut buf).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
 }
 
+#[test]
+fn retry_interrupted() {
+    let mut calls = 0;
+    let r = io::retry_interrupted(|| {
+        calls += 1;
+        if calls < 3 { Err(io::Error::from(io::ErrorKind::Interrupted)) } else { Ok(calls) }
+    });
+    assert_eq!(r.unwrap(), 3);
+
+    let mut calls = 0;
+    let r = io::retry_interrupted(|| -> io::Result<()> {
+        calls += 1;
+        Err(io::Error::from(io::ErrorKind::WouldBlock))
+    });
+    assert_eq!(r.unwrap_err().kind(), io::ErrorKind::WouldBlock);
+    assert_eq!(calls, 1);
+}
+
 #[test]
 fn read_exact_slice() {
     let mut buf = [0; 4];
@@ -187,6 +205,31 @@ fn read_exact_slice() {
     assert_eq!(c, b"9");
 }
 
+#[test]
+fn read_exact_vectored() {
+    let mut a = [0; 2];
+    let mut b = [0; 3];
+
+    let mut c = Cursor::new(&b""[..]);
+    let bufs = &mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    assert_eq!(c.read_exact_vectored(bufs).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+
+    // Empty buffers need no data at all.
+    let bufs = &mut [IoSliceMut::new(&mut a[..0]), IoSliceMut::new(&mut b[..0])];
+    c.read_exact_vectored(bufs).unwrap();
+
+    // `Chain` stops each read at the boundary between its readers, so this needs several calls
+    // to `read_vectored`.
+    let mut c = Cursor::new(&b"1"[..]).chain(Cursor::new(&b"23456"[..]));
+    let bufs = &mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    c.read_exact_vectored(bufs).unwrap();
+    assert_eq!(&a, b"12");
+    assert_eq!(&b, b"345");
+
+    let bufs = &mut [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    assert_eq!(c.read_exact_vectored(bufs).unwrap_err().kind(), io::ErrorKind::UnexpectedEof);
+}
+
 #[test]
 fn read_buf_exact() {
     let buf: &mut [_] = &mut [0; 4];
@@ -614,6 +657,64 @@ fn test_write_all_vectored() {
     }
 }
 
+#[test]
+fn test_write_all_vectored_errors() {
+    // Writes one byte per call, after first failing with `Interrupted`, and writes nothing once
+    // `limit` bytes have been written.
+    struct FlakyWriter {
+        interrupt: bool,
+        limit: usize,
+        calls: usize,
+        written: Vec<u8>,
+    }
+
+    impl Write for FlakyWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.write_vectored(&[IoSlice::new(buf)])
+        }
+
+        fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+            self.calls += 1;
+            if crate::mem::take(&mut self.interrupt) {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            match bufs.iter().find(|buf| !buf.is_empty()) {
+                Some(buf) if self.written.len() < self.limit => {
+                    self.written.push(buf[0]);
+                    Ok(1)
+                }
+                _ => Ok(0),
+            }
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let new_writer = |limit| FlakyWriter { interrupt: true, limit, calls: 0, written: Vec::new() };
+
+    // Buffers without data never reach `write_vectored`.
+    let mut writer = new_writer(usize::MAX);
+    let bufs = &mut [IoSlice::new(&[]), IoSlice::new(&[])];
+    assert!(writer.write_all_vectored(bufs).is_ok());
+    assert_eq!(writer.calls, 0);
+
+    // `Interrupted` is retried and every short write advances the buffers.
+    let mut writer = new_writer(usize::MAX);
+    let bufs = &mut [IoSlice::new(&[1, 2]), IoSlice::new(&[]), IoSlice::new(&[3])];
+    assert!(writer.write_all_vectored(bufs).is_ok());
+    assert_eq!(writer.written, [1, 2, 3]);
+    assert_eq!(writer.calls, 4);
+
+    // A write of zero bytes before all data is written is an error.
+    let mut writer = new_writer(2);
+    let bufs = &mut [IoSlice::new(&[1]), IoSlice::new(&[2, 3])];
+    let err = writer.write_all_vectored(bufs).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    assert_eq!(writer.written, [1, 2]);
+}
+
 // Issue 94981
 #[test]
 #[should_panic = "number of read bytes exceeds limit"]
@@ -3,6 +3,7 @@
 #[cfg(test)]
 mod tests;
 
+use crate::collections::VecDeque;
 use crate::fmt;
 use crate::io::{
     self, BorrowedCursor, BufRead, IoSlice, IoSliceMut, Read, Seek, SeekFrom, SizeHint, Write,
@@ -318,3 +319,197 @@ fn flush(&mut self) -> io::Result<()> {
         Ok(())
     }
 }
+
+/// A writer which moves data into the void like [`Sink`], but also keeps track of a stream
+/// position so that it can be used where [`Write`] + [`Seek`] is required.
+///
+/// Writing advances the position by the number of bytes written, and the length of the stream
+/// is the furthest position written to. Seeking past the end is allowed, as it is for files,
+/// and does not change the length until something is written there.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(seek_sink)]
+/// use std::io::{Seek, SeekFrom, SeekSink, Write};
+///
+/// let mut sink = SeekSink::new();
+/// sink.write_all(b"hello world")?;
+/// assert_eq!(sink.stream_position()?, 11);
+///
+/// sink.seek(SeekFrom::Start(6))?;
+/// sink.write_all(b"rust")?;
+/// assert_eq!(sink.stream_position()?, 10);
+/// assert_eq!(sink.seek(SeekFrom::End(0))?, 11);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[unstable(feature = "seek_sink", issue = "none")]
+#[derive(Clone, Debug, Default)]
+pub struct SeekSink {
+    pos: u64,
+    len: u64,
+}
+
+impl SeekSink {
+    /// Creates a new `SeekSink` with an empty stream, positioned at the start.
+    #[must_use]
+    #[unstable(feature = "seek_sink", issue = "none")]
+    pub const fn new() -> SeekSink {
+        SeekSink { pos: 0, len: 0 }
+    }
+
+    fn advance(&mut self, n: usize) -> io::Result<usize> {
+        match self.pos.checked_add(n as u64) {
+            Some(pos) => {
+                self.pos = pos;
+                self.len = self.len.max(pos);
+                Ok(n)
+            }
+            None => Err(io::const_io_error!(
+                io::ErrorKind::InvalidInput,
+                "write would move the stream position past u64::MAX",
+            )),
+        }
+    }
+}
+
+#[unstable(feature = "seek_sink", issue = "none")]
+impl Write for SeekSink {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.advance(buf.len())
+    }
+
+    #[inline]
+    fn write_vectored(&mut self, bufs: &[IoSlice<'_>]) -> io::Result<usize> {
+        let total_len = bufs.iter().map(|b| b.len()).sum();
+        self.advance(total_len)
+    }
+
+    #[inline]
+    fn is_write_vectored(&self) -> bool {
+        true
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[unstable(feature = "seek_sink", issue = "none")]
+impl Seek for SeekSink {
+    fn seek(&mut self, style: SeekFrom) -> io::Result<u64> {
+        let (base_pos, offset) = match style {
+            SeekFrom::Start(n) => {
+                self.pos = n;
+                return Ok(n);
+            }
+            SeekFrom::End(n) => (self.len, n),
+            SeekFrom::Current(n) => (self.pos, n),
+        };
+        match base_pos.checked_add_signed(offset) {
+            Some(n) => {
+                self.pos = n;
+                Ok(self.pos)
+            }
+            None => Err(io::const_io_error!(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+
+    fn stream_len(&mut self) -> io::Result<u64> {
+        Ok(self.len)
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+/// A reader which reads from a sequence of readers, one after the other.
+///
+/// This struct is generally created by calling [`chain_n()`]. Please
+/// see the documentation of [`chain_n()`] for more details.
+#[unstable(feature = "io_chain_n", issue = "none")]
+#[derive(Debug)]
+pub struct ChainN<R> {
+    readers: VecDeque<R>,
+}
+
+/// Creates a reader that reads from each of the given readers in turn.
+///
+/// Each reader is read until it reaches EOF. It is then dropped and reading
+/// continues with the next one. This behaves like nested calls to
+/// [`Read::chain`], but works for any number of readers of the same type.
+///
+/// If all readers implement [`BufRead`], so does the returned reader.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(io_chain_n)]
+/// use std::io::{self, Read};
+///
+/// let parts: [&[u8]; 3] = [b"hello", b", ", b"world"];
+/// let mut s = String::new();
+/// io::chain_n(parts).read_to_string(&mut s).unwrap();
+/// assert_eq!(s, "hello, world");
+/// ```
+#[unstable(feature = "io_chain_n", issue = "none")]
+pub fn chain_n<I>(readers: I) -> ChainN<I::Item>
+where
+    I: IntoIterator,
+    I::Item: Read,
+{
+    ChainN { readers: readers.into_iter().collect() }
+}
+
+#[unstable(feature = "io_chain_n", issue = "none")]
+impl<R: Read> Read for ChainN<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while let Some(reader) = self.readers.front_mut() {
+            match reader.read(buf)? {
+                0 if !buf.is_empty() => drop(self.readers.pop_front()),
+                n => return Ok(n),
+            }
+        }
+        Ok(0)
+    }
+
+    fn read_vectored(&mut self, bufs: &mut [IoSliceMut<'_>]) -> io::Result<usize> {
+        while let Some(reader) = self.readers.front_mut() {
+            match reader.read_vectored(bufs)? {
+                0 if bufs.iter().any(|b| !b.is_empty()) => drop(self.readers.pop_front()),
+                n => return Ok(n),
+            }
+        }
+        Ok(0)
+    }
+}
+
+#[unstable(feature = "io_chain_n", issue = "none")]
+impl<R: BufRead> BufRead for ChainN<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        // Drop exhausted readers first, so that the buffer below is returned from a single
+        // borrow of the reader in front.
+        while let Some(reader) = self.readers.front_mut() {
+            if !reader.fill_buf()?.is_empty() {
+                break;
+            }
+            self.readers.pop_front();
+        }
+        match self.readers.front_mut() {
+            Some(reader) => reader.fill_buf(),
+            None => Ok(&[]),
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if let Some(reader) = self.readers.front_mut() {
+            reader.consume(amt);
+        }
+    }
+}
@@ -1,5 +1,8 @@
 use crate::io::prelude::*;
-use crate::io::{empty, repeat, sink, BorrowedBuf, Empty, Repeat, SeekFrom, Sink};
+use crate::io::{
+    chain_n, empty, repeat, sink, BorrowedBuf, Cursor, Empty, IoSlice, IoSliceMut, Repeat,
+    SeekFrom, SeekSink, Sink,
+};
 
 use crate::mem::MaybeUninit;
 
@@ -12,6 +15,32 @@ fn sink_sinks() {
     assert_eq!(s.by_ref().write(&[0; 1024]).unwrap(), 1024);
 }
 
+#[test]
+fn seek_sink_tracks_position() {
+    let mut s = SeekSink::new();
+    assert_eq!(s.write(&[0; 8]).unwrap(), 8);
+    assert_eq!(s.stream_position().unwrap(), 8);
+    assert_eq!(s.stream_len().unwrap(), 8);
+
+    // Seeking back and overwriting moves the position but not the length.
+    assert_eq!(s.seek(SeekFrom::Current(-6)).unwrap(), 2);
+    assert_eq!(s.write_vectored(&[IoSlice::new(&[0; 2]), IoSlice::new(&[0])]).unwrap(), 3);
+    assert_eq!(s.stream_position().unwrap(), 5);
+    assert_eq!(s.stream_len().unwrap(), 8);
+
+    // Seeking past the end only changes the length once something is written there.
+    assert_eq!(s.seek(SeekFrom::End(4)).unwrap(), 12);
+    assert_eq!(s.stream_len().unwrap(), 8);
+    s.write_all(&[0]).unwrap();
+    assert_eq!(s.stream_len().unwrap(), 13);
+
+    assert!(s.seek(SeekFrom::Current(-14)).is_err());
+    assert_eq!(s.stream_position().unwrap(), 13);
+    assert_eq!(s.seek(SeekFrom::Start(u64::MAX)).unwrap(), u64::MAX);
+    assert!(s.write(&[0]).is_err());
+    assert_eq!(s.write(&[]).unwrap(), 0);
+}
+
 #[test]
 fn empty_reads() {
     let mut e = empty();
@@ -95,3 +124,46 @@ fn const_utils() {
     const _: Repeat = repeat(b'c');
     const _: Sink = sink();
 }
+
+#[test]
+fn chain_n_reads_in_order() {
+    let parts: Vec<&[u8]> = vec![b"ab", b"", b"cde", b"f"];
+    let mut s = String::new();
+    chain_n(parts).read_to_string(&mut s).unwrap();
+    assert_eq!(s, "abcdef");
+
+    let mut c = chain_n(Vec::<&[u8]>::new());
+    assert_eq!(c.read(&mut [0; 4]).unwrap(), 0);
+
+    // Reads never span two readers, and an empty buffer doesn't skip a reader.
+    let mut c = chain_n([&b"ab"[..], b"cd"]);
+    let mut buf = [0; 4];
+    assert_eq!(c.read(&mut []).unwrap(), 0);
+    assert_eq!(c.read(&mut buf).unwrap(), 2);
+    assert_eq!(&buf[..2], b"ab");
+    assert_eq!(c.read(&mut buf).unwrap(), 2);
+    assert_eq!(&buf[..2], b"cd");
+    assert_eq!(c.read(&mut buf).unwrap(), 0);
+}
+
+#[test]
+fn chain_n_read_vectored() {
+    let mut c = chain_n([&b""[..], b"abc", b"de"]);
+    let (mut a, mut b) = ([0; 2], [0; 2]);
+    let mut bufs = [IoSliceMut::new(&mut a), IoSliceMut::new(&mut b)];
+    assert_eq!(c.read_vectored(&mut bufs).unwrap(), 3);
+    assert_eq!(c.read_vectored(&mut bufs).unwrap(), 2);
+    assert_eq!(c.read_vectored(&mut bufs).unwrap(), 0);
+}
+
+#[test]
+fn chain_n_buf_read() {
+    let mut c = chain_n([Cursor::new("one\ntw"), Cursor::new(""), Cursor::new("o\nthree")]);
+    let mut line = String::new();
+    let mut lines = Vec::new();
+    while c.read_line(&mut line).unwrap() != 0 {
+        lines.push(crate::mem::take(&mut line));
+    }
+    assert_eq!(lines, ["one\n", "two\n", "three"]);
+    assert!(c.fill_buf().unwrap().is_empty());
+}
@@ -2380,6 +2380,67 @@ fn _strip_prefix(&self, base: &Path) -> Result<&Path, StripPrefixError> {
             .ok_or(StripPrefixError(()))
     }
 
+    /// Returns a relative path that, when joined onto `base`, refers to `self`.
+    ///
+    /// The components both paths have in common are skipped, every remaining
+    /// component of `base` becomes a `..`, and the remaining components of
+    /// `self` are appended. This is a purely lexical operation: the file
+    /// system is not accessed, so symlinks in `base` are not taken into account.
+    ///
+    /// Returns [`None`] if only one of the paths is absolute, if they have
+    /// different [prefixes] (such as different drive letters on Windows), or if
+    /// the part of `base` not shared with `self` contains a `..` component.
+    ///
+    /// [prefixes]: Prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(path_relative_to)]
+    /// use std::path::{Path, PathBuf};
+    ///
+    /// let path = Path::new("/usr/share/doc");
+    ///
+    /// assert_eq!(path.relative_to("/usr/lib"), Some(PathBuf::from("../share/doc")));
+    /// assert_eq!(path.relative_to("/usr"), Some(PathBuf::from("share/doc")));
+    /// assert_eq!(path.relative_to("/usr/share/doc"), Some(PathBuf::new()));
+    /// assert_eq!(Path::new("/usr").relative_to(path), Some(PathBuf::from("../..")));
+    ///
+    /// assert_eq!(path.relative_to("usr"), None);
+    /// assert_eq!(path.relative_to("/usr/../lib"), None);
+    /// ```
+    #[unstable(feature = "path_relative_to", issue = "none")]
+    #[must_use]
+    pub fn relative_to<P: AsRef<Path>>(&self, base: P) -> Option<PathBuf> {
+        self._relative_to(base.as_ref())
+    }
+
+    fn _relative_to(&self, base: &Path) -> Option<PathBuf> {
+        let mut path = self.components().filter(|c| *c != Component::CurDir).peekable();
+        let mut base = base.components().filter(|c| *c != Component::CurDir).peekable();
+        while path.peek().is_some() && path.peek() == base.peek() {
+            path.next();
+            base.next();
+        }
+
+        let mut relative = PathBuf::new();
+        for component in base {
+            match component {
+                Component::Normal(_) => relative.push(Component::ParentDir),
+                // A different root can't be reached with `..`, and going back out of a
+                // `..` would require knowing the name of the directory it left.
+                _ => return None,
+            }
+        }
+        for component in path {
+            match component {
+                Component::Prefix(_) | Component::RootDir => return None,
+                _ => relative.push(component),
+            }
+        }
+        Some(relative)
+    }
+
     /// Determines whether `base` is a prefix of `self`.
     ///
     /// Only considers whole path components to match.
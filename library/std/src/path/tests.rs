@@ -1737,6 +1737,44 @@ macro_rules! ord(
     ord!(Equal, "foo/bar", "foo/bar//");
 }
 
+#[test]
+fn test_relative_to() {
+    macro_rules! rel {
+        ($path:expr, $base:expr, $expected:expr) => {
+            assert_eq!(
+                Path::new($path).relative_to($base),
+                $expected.map(PathBuf::from),
+                "relative_to({:?}, {:?})",
+                $path,
+                $base
+            );
+        };
+    }
+
+    rel!("/a/b/c", "/a/b/c", Some(""));
+    rel!("/a/b/c", "/a/b", Some("c"));
+    rel!("/a/b", "/a/b/c", Some(".."));
+    rel!("/a/b/c", "/a/d/e", Some("../../b/c"));
+    rel!("/a/b/c", "/", Some("a/b/c"));
+    rel!("/", "/a/b", Some("../.."));
+    rel!("/a/./b/", "/a/b/.", Some(""));
+    rel!("a/b", "a/c", Some("../b"));
+    rel!("./a/b", "a", Some("b"));
+    rel!("a/../b", "a", Some("../b"));
+    rel!("", "a/b", Some("../.."));
+
+    rel!("/a/b", "a/b", None::<&str>);
+    rel!("a/b", "/a/b", None::<&str>);
+    rel!("/a/b", "/a/../c", None::<&str>);
+    rel!("a", "../b", None::<&str>);
+
+    if cfg!(windows) {
+        rel!(r"C:\a\b", r"C:\a\c", Some(r"..\b"));
+        rel!(r"C:\a\b", r"D:\a\b", None::<&str>);
+        rel!(r"\\server\share\a", r"\\server\other\a", None::<&str>);
+    }
+}
+
 #[test]
 #[cfg(unix)]
 fn test_unix_absolute() {
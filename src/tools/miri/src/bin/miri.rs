@@ -419,6 +419,12 @@ fn main() {
         } else if arg == "-Zmiri-ignore-leaks" {
             miri_config.ignore_leaks = true;
             miri_config.collect_leak_backtraces = false;
+        } else if let Some(param) = arg.strip_prefix("-Zmiri-report-leak-threshold=") {
+            let threshold = match param.parse::<u64>() {
+                Ok(i) => i,
+                Err(err) => show_error!("-Zmiri-report-leak-threshold requires a `u64`: {}", err),
+            };
+            miri_config.report_leak_threshold = threshold;
         } else if arg == "-Zmiri-panic-on-unsupported" {
             miri_config.panic_on_unsupported = true;
         } else if arg == "-Zmiri-tag-raw-pointers" {
@@ -4,7 +4,7 @@
 use log::trace;
 
 use rustc_errors::{DiagnosticBuilder, DiagnosticMessage, Level};
-use rustc_span::{SpanData, Symbol, DUMMY_SP};
+use rustc_span::{sym, SpanData, Symbol, DUMMY_SP};
 use rustc_target::abi::{Align, Size};
 
 use crate::borrow_tracker::stacked_borrows::diagnostics::TagHistory;
@@ -328,7 +328,11 @@ pub fn report_error<'tcx, 'mir>(
                         if let Some(span) = ecx.machine.allocated_span(*alloc_id) {
                             helps.push((Some(span), format!("{:?} was allocated here:", alloc_id)));
                         }
-                        if let Some(span) = ecx.machine.deallocated_span(*alloc_id) {
+                        if ecx.machine.was_unmapped(*alloc_id) {
+                            if let Some(span) = ecx.machine.deallocated_span(*alloc_id) {
+                                helps.push((Some(span), format!("{:?} was unmapped here:", alloc_id)));
+                            }
+                        } else if let Some(span) = ecx.machine.deallocated_span(*alloc_id) {
                             helps.push((Some(span), format!("{:?} was deallocated here:", alloc_id)));
                         }
                     }
@@ -336,6 +340,11 @@ pub fn report_error<'tcx, 'mir>(
                         helps.push((None, format!("this means these two types are not *guaranteed* to be ABI-compatible across all targets")));
                         helps.push((None, format!("if you think this code should be accepted anyway, please report an issue")));
                     }
+                    _ if ecx.active_thread_stack().last().is_some_and(|frame| {
+                        ecx.tcx.is_diagnostic_item(sym::hint_assert_unchecked, frame.instance.def_id())
+                    }) => {
+                        helps.push((None, format!("`std::hint::assert_unchecked` was called with a condition that is `false`, violating its safety contract")));
+                    }
                     _ => {},
                 }
                 helps
@@ -370,7 +379,24 @@ pub fn report_error<'tcx, 'mir>(
         _ => {}
     }
 
-    msg.insert(0, ecx.format_error(e));
+    // Accesses to memory that was released by `munmap` get their own message, since the generic
+    // one talks about the memory being freed.
+    let unmapped = match e.kind() {
+        UndefinedBehavior(PointerUseAfterFree(alloc_id, _))
+            if ecx.machine.was_unmapped(*alloc_id) =>
+            Some(*alloc_id),
+        _ => None,
+    };
+    msg.insert(
+        0,
+        match unmapped {
+            Some(alloc_id) =>
+                format!(
+                    "attempted to access memory after munmap: {alloc_id:?} has been unmapped, so this pointer is dangling"
+                ),
+            None => ecx.format_error(e),
+        },
+    );
 
     report_msg(
         DiagLevel::Error,
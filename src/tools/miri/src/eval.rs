@@ -103,6 +103,8 @@ pub struct MiriConfig {
     pub isolated_op: IsolatedOp,
     /// Determines if memory leaks should be ignored.
     pub ignore_leaks: bool,
+    /// Leaked allocations smaller than this many bytes are not reported.
+    pub report_leak_threshold: u64,
     /// Environment variables that should always be forwarded from the host.
     pub forwarded_env_vars: Vec<String>,
     /// Command-line arguments passed to the interpreted program.
@@ -166,6 +168,7 @@ fn default() -> MiriConfig {
             check_abi: true,
             isolated_op: IsolatedOp::Reject(RejectOpWith::Abort),
             ignore_leaks: false,
+            report_leak_threshold: 0,
             forwarded_env_vars: vec![],
             args: vec![],
             seed: None,
@@ -422,8 +425,9 @@ pub fn eval_entry<'tcx>(
     entry_type: EntryFnType,
     config: MiriConfig,
 ) -> Option<i64> {
-    // Copy setting before we move `config`.
+    // Copy settings before we move `config`.
     let ignore_leaks = config.ignore_leaks;
+    let report_leak_threshold = config.report_leak_threshold;
 
     let mut ecx = match create_ecx(tcx, entry_id, entry_type, &config) {
         Ok(v) => v,
@@ -468,7 +472,8 @@ pub fn eval_entry<'tcx>(
         }
         // Check for memory leaks.
         info!("Additional static roots: {:?}", ecx.machine.static_roots);
-        let leaks = ecx.find_leaked_allocations(&ecx.machine.static_roots);
+        let mut leaks = ecx.find_leaked_allocations(&ecx.machine.static_roots);
+        leaks.retain(|(_, _, alloc)| alloc.size().bytes() >= report_leak_threshold);
         if !leaks.is_empty() {
             report_leaks(&ecx, leaks);
             let leak_message = "the evaluated program leaked memory, pass `-Zmiri-ignore-leaks` to disable this check";
@@ -305,6 +305,32 @@ pub fn and_then<T>(self, f: impl FnOnce(BorTag) -> Option<T>) -> Option<T> {
     }
 }
 
+/// The access permissions of a range of memory mapped by `mmap`, as set by `mmap` or `mprotect`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MmapProtection {
+    pub read: bool,
+    pub write: bool,
+}
+
+/// Extra per-allocation data for memory mapped by `mmap`.
+#[derive(Debug, Clone)]
+pub struct MmapExtra {
+    /// The protection of every byte of the mapping.
+    pub protection: RangeMap<MmapProtection>,
+}
+
+impl MmapExtra {
+    /// Returns whether all of `range` is mapped with `PROT_READ`.
+    pub fn is_readable(&self, range: AllocRange) -> bool {
+        self.protection.iter(range.start, range.size).all(|(_, prot)| prot.read)
+    }
+
+    /// Returns whether all of `range` is mapped with `PROT_WRITE`.
+    pub fn is_writable(&self, range: AllocRange) -> bool {
+        self.protection.iter(range.start, range.size).all(|(_, prot)| prot.write)
+    }
+}
+
 /// Extra per-allocation data
 #[derive(Debug, Clone)]
 pub struct AllocExtra<'tcx> {
@@ -324,6 +350,9 @@ pub struct AllocExtra<'tcx> {
     /// An offset inside this allocation that was deemed aligned even for symbolic alignment checks.
     /// Invariant: the promised alignment will never be less than the native alignment of this allocation.
     pub symbolic_alignment: Cell<Option<(Size, Align)>>,
+    /// State of an `mmap`ed allocation.
+    /// This is only set for allocations of kind `MiriMemoryKind::Mmap`.
+    pub mmap: Option<MmapExtra>,
 }
 
 impl VisitProvenance for AllocExtra<'_> {
@@ -334,6 +363,7 @@ fn visit_provenance(&self, visit: &mut VisitWith<'_>) {
             weak_memory,
             backtrace: _,
             symbolic_alignment: _,
+            mmap: _,
         } = self;
 
         borrow_tracker.visit_provenance(visit);
@@ -461,6 +491,8 @@ pub struct MiriMachine<'mir, 'tcx> {
     pub(crate) file_handler: shims::unix::FileHandler,
     /// The table of directory descriptors.
     pub(crate) dir_handler: shims::unix::DirHandler,
+    /// The `sigaction` registered for each signal number, stored in machine-managed memory.
+    pub(crate) signal_actions: FxHashMap<i32, MPlaceTy<'tcx, Provenance>>,
 
     /// This machine's monotone clock.
     pub(crate) clock: Clock,
@@ -552,6 +584,15 @@ pub struct MiriMachine<'mir, 'tcx> {
     /// The spans we will use to report where an allocation was created and deallocated in
     /// diagnostics.
     pub(crate) allocation_spans: RefCell<FxHashMap<AllocId, (Span, Option<Span>)>>,
+
+    /// The allocation and length of every region currently mapped by `mmap`, keyed by the
+    /// base address of the region.
+    pub(crate) mmap_regions: FxHashMap<u64, (AllocId, Size)>,
+
+    /// Allocations that were created by `mmap` and have since been released by `munmap`, so
+    /// that use-after-munmap can be diagnosed as such. Entries are removed by the provenance GC
+    /// once no pointer to the allocation is left, since it can then no longer be accessed.
+    pub(crate) unmapped_allocs: FxHashSet<AllocId>,
 }
 
 impl<'mir, 'tcx> MiriMachine<'mir, 'tcx> {
@@ -623,6 +664,7 @@ pub(crate) fn new(config: &MiriConfig, layout_cx: LayoutCx<'tcx, TyCtxt<'tcx>>)
             enforce_abi: config.check_abi,
             file_handler: FileHandler::new(config.mute_stdout_stderr),
             dir_handler: Default::default(),
+            signal_actions: FxHashMap::default(),
             layouts,
             threads: ThreadManager::default(),
             static_roots: Vec::new(),
@@ -677,6 +719,8 @@ pub(crate) fn new(config: &MiriConfig, layout_cx: LayoutCx<'tcx, TyCtxt<'tcx>>)
             stack_size,
             collect_leak_backtraces: config.collect_leak_backtraces,
             allocation_spans: RefCell::new(FxHashMap::default()),
+            mmap_regions: FxHashMap::default(),
+            unmapped_allocs: FxHashSet::default(),
         }
     }
 
@@ -807,6 +851,10 @@ pub(crate) fn deallocated_span(&self, alloc_id: AllocId) -> Option<SpanData> {
             .and_then(|(_allocated, deallocated)| *deallocated)
             .map(Span::data)
     }
+
+    pub(crate) fn was_unmapped(&self, alloc_id: AllocId) -> bool {
+        self.unmapped_allocs.contains(&alloc_id)
+    }
 }
 
 impl VisitProvenance for MiriMachine<'_, '_> {
@@ -822,6 +870,7 @@ fn visit_provenance(&self, visit: &mut VisitWith<'_>) {
             cmd_line,
             extern_statics,
             dir_handler,
+            signal_actions,
             borrow_tracker,
             data_race,
             intptrcast,
@@ -857,6 +906,8 @@ fn visit_provenance(&self, visit: &mut VisitWith<'_>) {
             stack_size: _,
             collect_leak_backtraces: _,
             allocation_spans: _,
+            mmap_regions: _,
+            unmapped_allocs: _,
         } = self;
 
         threads.visit_provenance(visit);
@@ -874,6 +925,9 @@ fn visit_provenance(&self, visit: &mut VisitWith<'_>) {
         for ptr in extern_statics.values() {
             ptr.visit_provenance(visit);
         }
+        for place in signal_actions.values() {
+            place.visit_provenance(visit);
+        }
     }
 }
 
@@ -1166,6 +1220,8 @@ fn adjust_allocation<'b>(
                 weak_memory: buffer_alloc,
                 backtrace,
                 symbolic_alignment: Cell::new(None),
+                // Set up by the `mmap` shim once the mapping has been initialized.
+                mmap: None,
             },
             |ptr| ecx.global_base_pointer(ptr),
         )?;
@@ -1264,6 +1320,14 @@ fn before_memory_read(
         (alloc_id, prov_extra): (AllocId, Self::ProvenanceExtra),
         range: AllocRange,
     ) -> InterpResult<'tcx> {
+        if let Some(mmap) = &alloc_extra.mmap {
+            if !mmap.is_readable(range) {
+                throw_ub_format!(
+                    "read access to {alloc_id:?} at {offset:#x}, which is mapped without PROT_READ",
+                    offset = range.start.bytes(),
+                );
+            }
+        }
         if let Some(data_race) = &alloc_extra.data_race {
             data_race.read(alloc_id, range, machine)?;
         }
@@ -1284,6 +1348,14 @@ fn before_memory_write(
         (alloc_id, prov_extra): (AllocId, Self::ProvenanceExtra),
         range: AllocRange,
     ) -> InterpResult<'tcx> {
+        if let Some(mmap) = &alloc_extra.mmap {
+            if !mmap.is_writable(range) {
+                throw_ub_format!(
+                    "write access to {alloc_id:?} at {offset:#x}, which is mapped without PROT_WRITE",
+                    offset = range.start.bytes(),
+                );
+            }
+        }
         if let Some(data_race) = &mut alloc_extra.data_race {
             data_race.write(alloc_id, range, machine)?;
         }
@@ -1317,6 +1389,9 @@ fn before_memory_deallocation(
         {
             *deallocated_at = Some(machine.current_span());
         }
+        if alloc_extra.mmap.is_some() {
+            machine.unmapped_allocs.insert(alloc_id);
+        }
         machine.intptrcast.get_mut().free_alloc_id(alloc_id);
         Ok(())
     }
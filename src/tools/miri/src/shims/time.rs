@@ -58,6 +58,11 @@ fn clock_gettime(
             }
             target => throw_unsup_format!("`clock_gettime` is not supported on target OS {target}"),
         }
+        // CPU-time clocks measure how long the process or thread has been running. Miri does not
+        // track that separately from the interpreter clock, so we treat them like the MONOTONIC
+        // clocks: they start at an arbitrary point and advance as the program executes.
+        relative_clocks.push(this.eval_libc_i32("CLOCK_PROCESS_CPUTIME_ID"));
+        relative_clocks.push(this.eval_libc_i32("CLOCK_THREAD_CPUTIME_ID"));
 
         let duration = if absolute_clocks.contains(&clk_id) {
             this.check_no_isolation("`clock_gettime` with `REALTIME` clocks")?;
@@ -12,6 +12,7 @@
 use shims::foreign_items::EmulateForeignItemResult;
 use shims::unix::fs::EvalContextExt as _;
 use shims::unix::mem::EvalContextExt as _;
+use shims::unix::signal::EvalContextExt as _;
 use shims::unix::sync::EvalContextExt as _;
 use shims::unix::thread::EvalContextExt as _;
 
@@ -259,6 +260,44 @@ fn emulate_foreign_item_inner(
                 let result = this.munmap(addr, length)?;
                 this.write_scalar(result, dest)?;
             }
+            // std uses `mprotect` to set up guard pages on memory that was not mapped by `mmap`;
+            // those calls are ignored below.
+            "mprotect" if !this.frame_in_std() => {
+                let [addr, length, prot] = this.check_shim(abi, Abi::C {unwind: false}, link_name, args)?;
+                let result = this.mprotect(addr, length, prot)?;
+                this.write_scalar(result, dest)?;
+            }
+            "madvise" => {
+                let [addr, length, advice] = this.check_shim(abi, Abi::C {unwind: false}, link_name, args)?;
+                let result = this.madvise(addr, length, advice)?;
+                this.write_scalar(result, dest)?;
+            }
+            "posix_madvise" => {
+                let [addr, length, advice] = this.check_shim(abi, Abi::C {unwind: false}, link_name, args)?;
+                let result = this.posix_madvise(addr, length, advice)?;
+                this.write_scalar(result, dest)?;
+            }
+            "mincore" => {
+                let [addr, length, vec] = this.check_shim(abi, Abi::C {unwind: false}, link_name, args)?;
+                let result = this.mincore(addr, length, vec)?;
+                this.write_scalar(result, dest)?;
+            }
+            "mlock" | "munlock" => {
+                let [addr, length] = this.check_shim(abi, Abi::C {unwind: false}, link_name, args)?;
+                let result = this.mlock_or_munlock(addr, length)?;
+                this.write_scalar(result, dest)?;
+            }
+            "mlockall" => {
+                let [_flags] = this.check_shim(abi, Abi::C {unwind: false}, link_name, args)?;
+                // Locking all current and future mappings is not something Miri can check.
+                this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOSYS")))?;
+                this.write_scalar(Scalar::from_i32(-1), dest)?;
+            }
+            "munlockall" => {
+                let [] = this.check_shim(abi, Abi::C {unwind: false}, link_name, args)?;
+                this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOSYS")))?;
+                this.write_scalar(Scalar::from_i32(-1), dest)?;
+            }
 
             "reallocarray" => {
                 // Currently this function does not exist on all Unixes, e.g. on macOS.
@@ -500,6 +539,13 @@ fn emulate_foreign_item_inner(
                 this.write_scalar(Scalar::from_i32(result), dest)?;
             }
 
+            // Signal handling
+            "sigaction" => {
+                let [signum, act, oldact] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let result = this.sigaction(signum, act, oldact)?;
+                this.write_scalar(Scalar::from_i32(result), dest)?;
+            }
+
             // Threading
             "pthread_create" => {
                 let [thread, attr, start, arg] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
@@ -595,6 +641,20 @@ fn emulate_foreign_item_inner(
                 }
             }
 
+            // Process spawning
+            "posix_spawn" | "posix_spawnp" => {
+                let [_pid, _path, _file_actions, _attrp, _argv, _envp] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                // There is only a single address space in Miri, so there is no way to create a
+                // child process. Fail like a system without process support would, instead of
+                // running into whatever unsupported syscall the libc implementation uses.
+                this.tcx.dcx().warn(
+                    "Miri does not support spawning child processes; consider mocking the process spawn in tests",
+                );
+                // `posix_spawn` reports errors through its return value, not through `errno`.
+                this.write_scalar(this.eval_libc("ENOSYS"), dest)?;
+            }
+
             // Incomplete shims that we "stub out" just to get pre-main initialization code to work.
             // These shims are enabled only when the caller is in the standard library.
             "pthread_attr_getguardsize"
@@ -649,8 +709,7 @@ fn emulate_foreign_item_inner(
                 let [_, _] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
                 this.write_null(dest)?;
             }
-            | "sigaction"
-            | "mprotect"
+            "mprotect"
             if this.frame_in_std() => {
                 let [_, _, _] = this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
                 this.write_null(dest)?;
@@ -15,6 +15,7 @@
 use rustc_target::abi::Size;
 
 use crate::shims::os_str::bytes_to_os_str;
+use crate::shims::unix::linux::fd::memfd::MemFd;
 use crate::*;
 use shims::time::system_time_to_duration;
 
@@ -1480,6 +1481,20 @@ fn closedir(&mut self, dirp_op: &OpTy<'tcx, Provenance>) -> InterpResult<'tcx, i
     fn ftruncate64(&mut self, fd: i32, length: i128) -> InterpResult<'tcx, Scalar<Provenance>> {
         let this = self.eval_context_mut();
 
+        // A memfd lives entirely in memory, so it can be truncated even with isolation enabled.
+        if let Some(memfd) = this
+            .machine
+            .file_handler
+            .handles
+            .get(&fd)
+            .and_then(|file_descriptor| file_descriptor.downcast_ref::<MemFd>())
+        {
+            let result = u64::try_from(length)
+                .map_err(|_| io::Error::from(ErrorKind::InvalidInput))
+                .and_then(|length| memfd.set_len(length));
+            return Ok(Scalar::from_i32(this.try_unwrap_io_result(result.map(|_| 0i32))?));
+        }
+
         // Reject if isolation is enabled.
         if let IsolatedOp::Reject(reject_with) = this.machine.isolated_op {
             this.reject_in_isolation("`ftruncate64`", reject_with)?;
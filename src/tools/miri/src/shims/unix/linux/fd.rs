@@ -5,12 +5,14 @@
 use crate::*;
 use epoll::{Epoll, EpollEvent};
 use event::Event;
+use memfd::MemFd;
 use socketpair::SocketPair;
 
 use shims::unix::fs::EvalContextExt as _;
 
 pub mod epoll;
 pub mod event;
+pub mod memfd;
 pub mod socketpair;
 
 impl<'mir, 'tcx: 'mir> EvalContextExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
@@ -208,6 +210,40 @@ fn eventfd(
         Ok(Scalar::from_i32(fd))
     }
 
+    /// This function creates an anonymous file that lives entirely in memory and returns a file
+    /// descriptor referring to it. The file starts out empty, and can be grown with `ftruncate`
+    /// or `write`. It can also be mapped with `mmap`, but Miri only supports `MAP_PRIVATE`
+    /// mappings of it.
+    ///
+    /// The `name` is only used for debugging purposes, and is ignored by Miri.
+    /// The `flags` may be bitwise ORed to change the behavior of `memfd_create`:
+    /// `MFD_CLOEXEC` - Set the close-on-exec (`FD_CLOEXEC`) flag on the new file descriptor.
+    /// Miri does not support `exec`, so this flag has no effect.
+    /// `MFD_ALLOW_SEALING`, `MFD_HUGETLB` - Miri does not support these.
+    ///
+    /// <https://man7.org/linux/man-pages/man2/memfd_create.2.html>
+    fn memfd_create(
+        &mut self,
+        name: &OpTy<'tcx, Provenance>,
+        flags: &OpTy<'tcx, Provenance>,
+    ) -> InterpResult<'tcx, Scalar<Provenance>> {
+        let this = self.eval_context_mut();
+
+        let name = this.read_pointer(name)?;
+        let flags = this.read_scalar(flags)?.to_u32()?;
+
+        // The name is unused, but it still has to be a valid C string.
+        this.read_c_str(name)?;
+
+        let mfd_cloexec = this.eval_libc_u32("MFD_CLOEXEC");
+        if flags & !mfd_cloexec != 0 {
+            throw_unsup_format!("memfd_create flags {flags:#x} are not implemented");
+        }
+
+        let fd = this.machine.file_handler.insert_fd(Box::new(MemFd::default()));
+        Ok(Scalar::from_i32(fd))
+    }
+
     /// Currently this function creates new `SocketPair`s without specifying the domain, type, or
     /// protocol of the new socket and these are stored in the socket values `sv` argument.
     ///
@@ -0,0 +1,139 @@
+use crate::shims::unix::fs::FileDescriptor;
+
+use rustc_const_eval::interpret::InterpResult;
+use rustc_middle::ty::TyCtxt;
+
+use std::cell::RefCell;
+use std::io::{self, SeekFrom};
+use std::rc::Rc;
+
+/// An anonymous in-memory file created by `memfd_create`.
+/// Duplicated file descriptors refer to the same file and share its offset, so all the state
+/// lives behind a shared pointer.
+///
+/// <https://man7.org/linux/man-pages/man2/memfd_create.2.html>
+#[derive(Debug, Clone, Default)]
+pub struct MemFd {
+    file: Rc<RefCell<MemFile>>,
+}
+
+/// The largest size a memfd can grow to. The contents live in the memory of the Miri process, so
+/// growing a file by a huge amount, e.g. with `ftruncate` or by writing far past its end, fails
+/// with `EFBIG` instead of making Miri itself run out of memory.
+const MAX_LEN: usize = 1 << 30;
+
+#[derive(Debug, Default)]
+struct MemFile {
+    /// The contents of the file.
+    data: Vec<u8>,
+    /// The offset at which the next read or write happens.
+    offset: u64,
+}
+
+impl MemFile {
+    /// Changes the size of the file to `len`, filling it with zeros if it grows.
+    fn resize(&mut self, len: usize) -> io::Result<()> {
+        if len > MAX_LEN {
+            return Err(io::Error::from(io::ErrorKind::FileTooLarge));
+        }
+        if let Some(additional) = len.checked_sub(self.data.len()) {
+            self.data
+                .try_reserve_exact(additional)
+                .map_err(|_| io::Error::from(io::ErrorKind::OutOfMemory))?;
+        }
+        self.data.resize(len, 0);
+        Ok(())
+    }
+}
+
+impl MemFd {
+    /// Returns a copy of the file contents starting at `offset` and at most `len` bytes long.
+    pub fn contents(&self, offset: u64, len: u64) -> Vec<u8> {
+        let file = self.file.borrow();
+        let start = usize::try_from(offset).unwrap_or(usize::MAX).min(file.data.len());
+        let end = usize::try_from(offset.saturating_add(len)).unwrap_or(usize::MAX);
+        file.data[start..end.min(file.data.len())].to_vec()
+    }
+
+    /// Changes the size of the file, filling it with zeros if it grows.
+    pub fn set_len(&self, len: u64) -> io::Result<()> {
+        let len = usize::try_from(len).map_err(|_| io::Error::from(io::ErrorKind::FileTooLarge))?;
+        self.file.borrow_mut().resize(len)
+    }
+}
+
+impl FileDescriptor for MemFd {
+    fn name(&self) -> &'static str {
+        "memfd"
+    }
+
+    fn read<'tcx>(
+        &mut self,
+        _communicate_allowed: bool,
+        bytes: &mut [u8],
+        _tcx: TyCtxt<'tcx>,
+    ) -> InterpResult<'tcx, io::Result<usize>> {
+        let mut file = self.file.borrow_mut();
+        let MemFile { data, offset } = &mut *file;
+        // Reading at or past the end of the file reads nothing.
+        let start = usize::try_from(*offset).unwrap_or(usize::MAX).min(data.len());
+        let len = bytes.len().min(data.len().saturating_sub(start));
+        bytes[..len].copy_from_slice(&data[start..][..len]);
+        *offset = offset.checked_add(u64::try_from(len).unwrap()).unwrap();
+        Ok(Ok(len))
+    }
+
+    fn write<'tcx>(
+        &self,
+        _communicate_allowed: bool,
+        bytes: &[u8],
+        _tcx: TyCtxt<'tcx>,
+    ) -> InterpResult<'tcx, io::Result<usize>> {
+        let mut file = self.file.borrow_mut();
+        let Ok(start) = usize::try_from(file.offset) else {
+            return Ok(Err(io::Error::from(io::ErrorKind::FileTooLarge)));
+        };
+        let Some(end) = start.checked_add(bytes.len()) else {
+            return Ok(Err(io::Error::from(io::ErrorKind::FileTooLarge)));
+        };
+        // Writing past the end of the file leaves a hole that reads as zeros.
+        if file.data.len() < end {
+            if let Err(err) = file.resize(end) {
+                return Ok(Err(err));
+            }
+        }
+        file.data[start..end].copy_from_slice(bytes);
+        file.offset = u64::try_from(end).unwrap();
+        Ok(Ok(bytes.len()))
+    }
+
+    fn seek<'tcx>(
+        &mut self,
+        _communicate_allowed: bool,
+        offset: SeekFrom,
+    ) -> InterpResult<'tcx, io::Result<u64>> {
+        let mut file = self.file.borrow_mut();
+        let new_offset = match offset {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) =>
+                u64::try_from(file.data.len()).unwrap().checked_add_signed(offset),
+            SeekFrom::Current(offset) => file.offset.checked_add_signed(offset),
+        };
+        let Some(new_offset) = new_offset else {
+            return Ok(Err(io::Error::from(io::ErrorKind::InvalidInput)));
+        };
+        file.offset = new_offset;
+        Ok(Ok(new_offset))
+    }
+
+    fn close<'tcx>(
+        self: Box<Self>,
+        _communicate_allowed: bool,
+    ) -> InterpResult<'tcx, io::Result<i32>> {
+        Ok(Ok(0))
+    }
+
+    fn dup(&mut self) -> io::Result<Box<dyn FileDescriptor>> {
+        Ok(Box::new(self.clone()))
+    }
+}
@@ -73,6 +73,12 @@ fn emulate_foreign_item_inner(
                 let result = this.eventfd(val, flag)?;
                 this.write_scalar(result, dest)?;
             }
+            "memfd_create" => {
+                let [name, flags] =
+                    this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
+                let result = this.memfd_create(name, flags)?;
+                this.write_scalar(result, dest)?;
+            }
             "mremap" => {
                 let [old_address, old_size, new_size, flags] =
                     this.check_shim(abi, Abi::C { unwind: false }, link_name, args)?;
@@ -1,7 +1,7 @@
 //! This follows the pattern in src/shims/unix/mem.rs: We only support uses of mremap that would
 //! correspond to valid uses of realloc.
 
-use crate::*;
+use crate::{helpers::round_to_next_multiple_of, *};
 use rustc_target::abi::Size;
 
 impl<'mir, 'tcx: 'mir> EvalContextExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
@@ -28,11 +28,11 @@ fn mremap(
         }
 
         if flags & this.eval_libc_i32("MREMAP_FIXED") != 0 {
-            throw_unsup_format!("Miri does not support mremap wth MREMAP_FIXED");
+            throw_unsup_format!("Miri does not support mremap with MREMAP_FIXED");
         }
 
         if flags & this.eval_libc_i32("MREMAP_DONTUNMAP") != 0 {
-            throw_unsup_format!("Miri does not support mremap wth MREMAP_DONTUNMAP");
+            throw_unsup_format!("Miri does not support mremap with MREMAP_DONTUNMAP");
         }
 
         if flags & this.eval_libc_i32("MREMAP_MAYMOVE") == 0 {
@@ -41,6 +41,30 @@ fn mremap(
             return Ok(this.eval_libc("MAP_FAILED"));
         }
 
+        // Like `mmap`, we work with whole pages.
+        let old_size = round_to_next_multiple_of(old_size, this.machine.page_size);
+        let new_size = round_to_next_multiple_of(new_size, this.machine.page_size);
+
+        // Keeping the number of pages is a no-op, as long as `old_address` really is the start of a
+        // mapping of that size. If it is not, we let `reallocate_ptr` below report the error.
+        if new_size == old_size
+            && let Ok((alloc_id, offset, _)) = this.ptr_try_get_alloc_id(old_address)
+            && offset == Size::ZERO
+            && this.is_alloc_live(alloc_id)
+            && this.get_alloc_info(alloc_id).0 == Size::from_bytes(old_size)
+            && this.get_alloc_extra(alloc_id)?.mmap.is_some()
+        {
+            return Ok(Scalar::from_maybe_pointer(old_address, this));
+        }
+
+        // The new mapping inherits the state of the old one. We take it out of the old mapping so
+        // that copying the contents over is not subject to its protection.
+        let old_mmap = match this.ptr_try_get_alloc_id(old_address) {
+            Ok((alloc_id, _, _)) if this.is_alloc_live(alloc_id) =>
+                this.get_alloc_extra_mut(alloc_id)?.0.mmap.take(),
+            _ => None,
+        };
+
         let align = this.machine.page_align();
         let ptr = this.reallocate_ptr(
             old_address,
@@ -58,6 +82,27 @@ fn mremap(
             )
             .unwrap();
         }
+        if let Some(MmapExtra { protection: old_protection }) = old_mmap {
+            // Pages past the end of the old mapping get the protection of its last page.
+            let (_, &last) = old_protection.iter_all().last().unwrap();
+            let mut new_protection = RangeMap::new(Size::from_bytes(new_size), last);
+            for (range, &protection) in old_protection.iter_all() {
+                let Some(len) = range.end.min(new_size).checked_sub(range.start) else {
+                    break;
+                };
+                let (start, len) = (Size::from_bytes(range.start), Size::from_bytes(len));
+                for (_, page_protection) in new_protection.iter_mut(start, len) {
+                    *page_protection = protection;
+                }
+            }
+            let (alloc_id, _, _) = this.ptr_get_alloc_id(ptr.into())?;
+            this.get_alloc_extra_mut(alloc_id)?.0.mmap =
+                Some(MmapExtra { protection: new_protection });
+            this.machine.mmap_regions.remove(&old_address.addr().bytes());
+            this.machine
+                .mmap_regions
+                .insert(ptr.addr().bytes(), (alloc_id, Size::from_bytes(new_size)));
+        }
 
         Ok(Scalar::from_pointer(ptr, this))
     }
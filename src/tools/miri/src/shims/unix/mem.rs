@@ -1,11 +1,14 @@
 //! This is an incomplete implementation of mmap/munmap which is restricted in order to be
 //! implementable on top of the existing memory system. The point of these function as-written is
 //! to allow memory allocators written entirely in Rust to be executed by Miri. This implementation
-//! does not support other uses of mmap such as file mappings.
+//! does not support other uses of mmap such as file mappings, except for private mappings of
+//! in-memory files created by `memfd_create`.
 //!
 //! mmap/munmap behave a lot like alloc/dealloc, and for simple use they are exactly
-//! equivalent. That is the only part we support: no MAP_FIXED or MAP_SHARED or anything
-//! else that goes beyond a basic allocation API.
+//! equivalent. That is the only part we support: no MAP_FIXED or anything else that goes
+//! beyond a basic allocation API. Anonymous MAP_SHARED mappings are accepted since Miri does
+//! not support fork, so there is no other process to share them with and they behave exactly
+//! like MAP_PRIVATE mappings.
 //!
 //! Note that in addition to only supporting malloc-like calls to mmap, we only support free-like
 //! calls to munmap, but for a very different reason. In principle, according to the man pages, it
@@ -14,6 +17,7 @@
 //! munmap shim which would partily unmap a region of address space previously mapped by mmap will
 //! report UB.
 
+use crate::shims::unix::linux::fd::memfd::MemFd;
 use crate::{helpers::round_to_next_multiple_of, *};
 use rustc_target::abi::Size;
 
@@ -65,9 +69,19 @@ fn mmap(
         // If a user tries to map a file, we want to loudly inform them that this is not going
         // to work. It is possible that POSIX gives us enough leeway to return an error, but the
         // outcome for the user (I need to add cfg(miri)) is the same, just more frustrating.
-        if fd != -1 {
-            throw_unsup_format!("Miri does not support file-backed memory mappings");
-        }
+        // The only exception are memfds, since those live entirely in Miri's memory anyway.
+        let memfd = if fd != -1 {
+            let Some(file_descriptor) = this.machine.file_handler.handles.get(&fd) else {
+                this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EBADF")))?;
+                return Ok(this.eval_libc("MAP_FAILED"));
+            };
+            let Some(memfd) = file_descriptor.downcast_ref::<MemFd>() else {
+                throw_unsup_format!("Miri does not support file-backed memory mappings");
+            };
+            Some(memfd.clone())
+        } else {
+            None
+        };
 
         // POSIX says:
         // [ENOTSUP]
@@ -76,23 +90,53 @@ fn mmap(
         // * The implementation does not support the combination of accesses requested in the
         // prot argument.
         //
-        // Miri doesn't support MAP_FIXED or any any protections other than PROT_READ|PROT_WRITE.
-        if flags & map_fixed != 0 || prot != prot_read | prot_write {
+        // Miri doesn't support MAP_FIXED or PROT_EXEC.
+        if flags & map_fixed != 0 || prot & !(prot_read | prot_write) != 0 {
             this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOTSUP")))?;
             return Ok(this.eval_libc("MAP_FAILED"));
         }
 
-        // Miri does not support shared mappings, or any of the other extensions that for example
-        // Linux has added to the flags arguments.
-        if flags != map_private | map_anonymous {
-            throw_unsup_format!(
-                "Miri only supports calls to mmap which set the flags argument to MAP_PRIVATE|MAP_ANONYMOUS"
-            );
-        }
+        // Flags that only affect performance are irrelevant to Miri, so we ignore them when
+        // checking which kind of mapping was requested.
+        let hint_flags =
+            if this.tcx.sess.target.os == "linux" { this.eval_libc_i32("MAP_POPULATE") } else { 0 };
+        let mapping_flags = flags & !hint_flags;
+
+        if memfd.is_some() {
+            // A private mapping of a file is a copy of its contents, and it is unspecified whether
+            // later changes to the file are visible in it. We cannot do better than that, since a
+            // shared mapping would require the file and the mapping to be the same memory.
+            if mapping_flags & map_shared != 0 {
+                throw_unsup_format!(
+                    "Miri does not support MAP_SHARED mappings of a memfd, only MAP_PRIVATE ones"
+                );
+            }
+            if mapping_flags != map_private {
+                throw_unsup_format!(
+                    "Miri only supports calls to mmap of a memfd which set the flags argument to MAP_PRIVATE"
+                );
+            }
+            #[allow(clippy::arithmetic_side_effects)] // PAGE_SIZE is nonzero
+            if offset % this.machine.page_size != 0 {
+                this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EINVAL")))?;
+                return Ok(this.eval_libc("MAP_FAILED"));
+            }
+        } else {
+            // Miri does not support any of the other extensions that for example Linux has added to
+            // the flags argument. Since there is only ever a single process, anonymous shared
+            // mappings are indistinguishable from private ones.
+            if mapping_flags != map_private | map_anonymous
+                && mapping_flags != map_shared | map_anonymous
+            {
+                throw_unsup_format!(
+                    "Miri only supports calls to mmap which set the flags argument to MAP_PRIVATE|MAP_ANONYMOUS or MAP_SHARED|MAP_ANONYMOUS"
+                );
+            }
 
-        // This is only used for file mappings, which we don't support anyway.
-        if offset != 0 {
-            throw_unsup_format!("Miri does not support non-zero offsets to mmap");
+            // This is only used for file mappings.
+            if offset != 0 {
+                throw_unsup_format!("Miri does not support non-zero offsets to anonymous mmap");
+            }
         }
 
         let align = this.machine.page_align();
@@ -107,6 +151,21 @@ fn mmap(
             std::iter::repeat(0u8).take(usize::try_from(map_length).unwrap()),
         )
         .unwrap();
+        // The part of the mapping that is backed by the file gets a copy of its contents, the rest
+        // stays zeroed.
+        if let Some(memfd) = memfd {
+            this.write_bytes_ptr(ptr.into(), memfd.contents(offset, map_length)).unwrap();
+        }
+        // Only now that the mapping is initialized do we start enforcing its protection.
+        let protection =
+            MmapProtection { read: prot & prot_read != 0, write: prot & prot_write != 0 };
+        let (alloc_id, _, _) = this.ptr_get_alloc_id(ptr.into())?;
+        let (alloc_extra, _) = this.get_alloc_extra_mut(alloc_id)?;
+        alloc_extra.mmap =
+            Some(MmapExtra { protection: RangeMap::new(Size::from_bytes(map_length), protection) });
+        this.machine
+            .mmap_regions
+            .insert(ptr.addr().bytes(), (alloc_id, Size::from_bytes(map_length)));
 
         Ok(Scalar::from_pointer(ptr, this))
     }
@@ -121,10 +180,9 @@ fn munmap(
         let addr = this.read_pointer(addr)?;
         let length = this.read_target_usize(length)?;
 
-        // addr must be a multiple of the page size, but apart from that munmap is just implemented
-        // as a dealloc.
-        #[allow(clippy::arithmetic_side_effects)] // PAGE_SIZE is nonzero
-        if addr.addr().bytes() % this.machine.page_size != 0 {
+        // addr must be the start of a region returned by mmap, but apart from that munmap is just
+        // implemented as a dealloc.
+        if !this.machine.mmap_regions.contains_key(&addr.addr().bytes()) {
             this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EINVAL")))?;
             return Ok(Scalar::from_i32(-1));
         }
@@ -135,7 +193,266 @@ fn munmap(
             Some((length, this.machine.page_align())),
             MemoryKind::Machine(MiriMemoryKind::Mmap),
         )?;
+        this.machine.mmap_regions.remove(&addr.addr().bytes());
 
         Ok(Scalar::from_i32(0))
     }
+
+    fn mprotect(
+        &mut self,
+        addr: &OpTy<'tcx, Provenance>,
+        length: &OpTy<'tcx, Provenance>,
+        prot: &OpTy<'tcx, Provenance>,
+    ) -> InterpResult<'tcx, Scalar<Provenance>> {
+        let this = self.eval_context_mut();
+
+        let addr = this.read_pointer(addr)?;
+        let length = this.read_target_usize(length)?;
+        let prot = this.read_scalar(prot)?.to_i32()?;
+
+        let prot_read = this.eval_libc_i32("PROT_READ");
+        let prot_write = this.eval_libc_i32("PROT_WRITE");
+
+        // addr must be a multiple of the page size
+        #[allow(clippy::arithmetic_side_effects)] // PAGE_SIZE is nonzero
+        if addr.addr().bytes() % this.machine.page_size != 0 || length == 0 {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EINVAL")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        // Just like mmap, we do not support PROT_EXEC.
+        if prot & !(prot_read | prot_write) != 0 {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOTSUP")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        let Some((alloc_id, offset, length)) = this.find_mmap_range(addr, length)? else {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOMEM")))?;
+            return Ok(Scalar::from_i32(-1));
+        };
+
+        let protection =
+            MmapProtection { read: prot & prot_read != 0, write: prot & prot_write != 0 };
+        let (alloc_extra, _) = this.get_alloc_extra_mut(alloc_id)?;
+        let mmap = alloc_extra.mmap.as_mut().unwrap();
+        for (_, page_protection) in mmap.protection.iter_mut(offset, length) {
+            *page_protection = protection;
+        }
+
+        Ok(Scalar::from_i32(0))
+    }
+
+    fn madvise(
+        &mut self,
+        addr: &OpTy<'tcx, Provenance>,
+        length: &OpTy<'tcx, Provenance>,
+        advice: &OpTy<'tcx, Provenance>,
+    ) -> InterpResult<'tcx, Scalar<Provenance>> {
+        let this = self.eval_context_mut();
+
+        let addr = this.read_pointer(addr)?;
+        let length = this.read_target_usize(length)?;
+        let advice = this.read_scalar(advice)?.to_i32()?;
+
+        // addr must be a multiple of the page size
+        #[allow(clippy::arithmetic_side_effects)] // PAGE_SIZE is nonzero
+        if addr.addr().bytes() % this.machine.page_size != 0 {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EINVAL")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        // Advice that changes what happens on fork cannot be meaningfully supported, since
+        // Miri does not support fork.
+        if this.tcx.sess.target.os == "linux"
+            && (advice == this.eval_libc_i32("MADV_DONTFORK")
+                || advice == this.eval_libc_i32("MADV_WIPEONFORK"))
+        {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOSYS")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        // All other advice we support is treated as a hint that does not affect the program. On
+        // Linux, MADV_DONTNEED actually discards the contents of private mappings, but Miri keeps
+        // them, which is what the program would see if the pages had not been discarded yet.
+        let hints = [
+            "MADV_NORMAL",
+            "MADV_RANDOM",
+            "MADV_SEQUENTIAL",
+            "MADV_WILLNEED",
+            "MADV_DONTNEED",
+            "MADV_FREE",
+        ];
+        if !hints.iter().any(|name| advice == this.eval_libc_i32(name)) {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EINVAL")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        if length == 0 {
+            return Ok(Scalar::from_i32(0));
+        }
+        if this.find_mmap_range(addr, length)?.is_none() {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOMEM")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        Ok(Scalar::from_i32(0))
+    }
+
+    fn posix_madvise(
+        &mut self,
+        addr: &OpTy<'tcx, Provenance>,
+        length: &OpTy<'tcx, Provenance>,
+        advice: &OpTy<'tcx, Provenance>,
+    ) -> InterpResult<'tcx, Scalar<Provenance>> {
+        let this = self.eval_context_mut();
+
+        let addr = this.read_pointer(addr)?;
+        let length = this.read_target_usize(length)?;
+        let advice = this.read_scalar(advice)?.to_i32()?;
+
+        // Unlike madvise, this returns the error number rather than setting errno.
+        #[allow(clippy::arithmetic_side_effects)] // PAGE_SIZE is nonzero
+        if addr.addr().bytes() % this.machine.page_size != 0 {
+            return Ok(this.eval_libc("EINVAL"));
+        }
+
+        // All of the POSIX advice values are hints that do not affect the program. In particular,
+        // unlike MADV_DONTNEED, POSIX_MADV_DONTNEED never discards data.
+        let hints = [
+            "POSIX_MADV_NORMAL",
+            "POSIX_MADV_RANDOM",
+            "POSIX_MADV_SEQUENTIAL",
+            "POSIX_MADV_WILLNEED",
+            "POSIX_MADV_DONTNEED",
+        ];
+        if !hints.iter().any(|name| advice == this.eval_libc_i32(name)) {
+            return Ok(this.eval_libc("EINVAL"));
+        }
+
+        if length != 0 && this.find_mmap_range(addr, length)?.is_none() {
+            return Ok(this.eval_libc("ENOMEM"));
+        }
+
+        Ok(Scalar::from_i32(0))
+    }
+
+    fn mincore(
+        &mut self,
+        addr: &OpTy<'tcx, Provenance>,
+        length: &OpTy<'tcx, Provenance>,
+        vec: &OpTy<'tcx, Provenance>,
+    ) -> InterpResult<'tcx, Scalar<Provenance>> {
+        let this = self.eval_context_mut();
+
+        let addr = this.read_pointer(addr)?;
+        let length = this.read_target_usize(length)?;
+        let vec = this.read_pointer(vec)?;
+
+        // addr must be a multiple of the page size
+        #[allow(clippy::arithmetic_side_effects)] // PAGE_SIZE is nonzero
+        if addr.addr().bytes() % this.machine.page_size != 0 || length == 0 {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EINVAL")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        let Some((_, _, length)) = this.find_mmap_range(addr, length)? else {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOMEM")))?;
+            return Ok(Scalar::from_i32(-1));
+        };
+
+        // The kernel reports a bad `vec` by returning EFAULT, so we have to check that it points
+        // to enough writable memory rather than letting the write below fail. That includes the
+        // protection of `vec` if it points into a mapping.
+        #[allow(clippy::arithmetic_side_effects)] // PAGE_SIZE is nonzero
+        let pages = length.bytes() / this.machine.page_size;
+        let vec_len = Size::from_bytes(pages);
+        let writable = match this.ptr_try_get_alloc_id(vec) {
+            Ok((alloc_id, offset, _)) => {
+                let (size, _, kind) = this.get_alloc_info(alloc_id);
+                kind == AllocKind::LiveData
+                    && offset.checked_add(vec_len, this).is_some_and(|end| end <= size)
+                    && this.get_alloc_mutability(alloc_id)?.is_mut()
+                    && this
+                        .get_alloc_extra(alloc_id)?
+                        .mmap
+                        .as_ref()
+                        .map_or(true, |mmap| mmap.is_writable(alloc_range(offset, vec_len)))
+            }
+            Err(_) => false,
+        };
+        if !writable {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EFAULT")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        // Miri does not model paging, so we report every page as resident. That is always a
+        // valid answer since the result is only a snapshot that may be outdated on return.
+        this.write_bytes_ptr(vec, std::iter::repeat(1u8).take(usize::try_from(pages).unwrap()))?;
+
+        Ok(Scalar::from_i32(0))
+    }
+
+    /// Implements both `mlock` and `munlock`. Miri does not model swapping, so this only checks
+    /// that the arguments are sensible.
+    fn mlock_or_munlock(
+        &mut self,
+        addr: &OpTy<'tcx, Provenance>,
+        length: &OpTy<'tcx, Provenance>,
+    ) -> InterpResult<'tcx, Scalar<Provenance>> {
+        let this = self.eval_context_mut();
+
+        let addr = this.read_pointer(addr)?;
+        let length = this.read_target_usize(length)?;
+
+        if length == 0 {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("EINVAL")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        // Unlike most of the other functions here, this is commonly used on heap memory, so we
+        // accept any range inside a single live allocation.
+        let in_bounds = match this.ptr_try_get_alloc_id(addr) {
+            Ok((alloc_id, offset, _)) => {
+                let (size, _, kind) = this.get_alloc_info(alloc_id);
+                kind == AllocKind::LiveData
+                    && offset
+                        .checked_add(Size::from_bytes(length), this)
+                        .is_some_and(|end| end <= size)
+            }
+            Err(_) => false,
+        };
+        if !in_bounds {
+            this.set_last_error(Scalar::from_i32(this.eval_libc_i32("ENOMEM")))?;
+            return Ok(Scalar::from_i32(-1));
+        }
+
+        Ok(Scalar::from_i32(0))
+    }
+}
+
+impl<'mir, 'tcx: 'mir> EvalContextPrivExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
+trait EvalContextPrivExt<'mir, 'tcx: 'mir>: crate::MiriInterpCxExt<'mir, 'tcx> {
+    /// Finds the mapping that `addr..addr+length` lies in, with `length` rounded up to a whole
+    /// number of pages. Returns the allocation, the offset of `addr` in it, and the rounded
+    /// length, or `None` if the range is not entirely inside a single live `mmap`ed allocation.
+    fn find_mmap_range(
+        &self,
+        addr: Pointer<Option<Provenance>>,
+        length: u64,
+    ) -> InterpResult<'tcx, Option<(AllocId, Size, Size)>> {
+        let this = self.eval_context_ref();
+
+        let length = Size::from_bytes(round_to_next_multiple_of(length, this.machine.page_size));
+        let Ok((alloc_id, offset, _)) = this.ptr_try_get_alloc_id(addr) else {
+            return Ok(None);
+        };
+        if !this.is_alloc_live(alloc_id) || this.get_alloc_extra(alloc_id)?.mmap.is_none() {
+            return Ok(None);
+        }
+        let (size, _, _) = this.get_alloc_info(alloc_id);
+        if offset.checked_add(length, this).map_or(true, |end| end > size) {
+            return Ok(None);
+        }
+        Ok(Some((alloc_id, offset, length)))
+    }
 }
@@ -2,6 +2,7 @@
 
 mod fs;
 mod mem;
+mod signal;
 mod sync;
 mod thread;
 
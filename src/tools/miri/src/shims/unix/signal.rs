@@ -0,0 +1,69 @@
+use std::iter;
+
+use crate::*;
+
+/// Signal numbers must be smaller than this. This is `_NSIG` on Linux; the other supported targets
+/// have fewer signals, but accepting a few extra numbers there is harmless.
+const NSIG: i32 = 65;
+
+impl<'mir, 'tcx> EvalContextExt<'mir, 'tcx> for crate::MiriInterpCx<'mir, 'tcx> {}
+pub trait EvalContextExt<'mir, 'tcx: 'mir>: crate::MiriInterpCxExt<'mir, 'tcx> {
+    /// Registers and queries signal actions. Miri never delivers signals, so registered handlers
+    /// are never invoked; this only makes sure that code which installs and later restores
+    /// handlers observes consistent values.
+    fn sigaction(
+        &mut self,
+        signum: &OpTy<'tcx, Provenance>,
+        act: &OpTy<'tcx, Provenance>,
+        oldact: &OpTy<'tcx, Provenance>,
+    ) -> InterpResult<'tcx, i32> {
+        let this = self.eval_context_mut();
+
+        let signum = this.read_scalar(signum)?.to_i32()?;
+        let act_ptr = this.read_pointer(act)?;
+        let oldact_ptr = this.read_pointer(oldact)?;
+        let sigaction_layout = this.libc_ty_layout("sigaction");
+
+        // The actions for `SIGKILL` and `SIGSTOP` cannot be changed.
+        if signum <= 0
+            || signum >= NSIG
+            || (!this.ptr_is_null(act_ptr)?
+                && (signum == this.eval_libc_i32("SIGKILL")
+                    || signum == this.eval_libc_i32("SIGSTOP")))
+        {
+            let einval = this.eval_libc("EINVAL");
+            this.set_last_error(einval)?;
+            return Ok(-1);
+        }
+
+        // Copy the new action before writing the old one, since `act` and `oldact` may alias.
+        let new_action = if this.ptr_is_null(act_ptr)? {
+            None
+        } else {
+            let act = this.deref_pointer_as(act, sigaction_layout)?;
+            let place = this.allocate(sigaction_layout, MiriMemoryKind::Machine.into())?;
+            this.copy_op(&act, &place, /*allow_transmute*/ false)?;
+            Some(place)
+        };
+
+        if !this.ptr_is_null(oldact_ptr)? {
+            let oldact = this.deref_pointer_as(oldact, sigaction_layout)?;
+            if let Some(old_action) = this.machine.signal_actions.get(&signum).cloned() {
+                this.copy_op(&old_action, &oldact, /*allow_transmute*/ false)?;
+            } else {
+                // Nothing was registered yet, so this signal has its default action. An all-zero
+                // `sigaction` is `SIG_DFL` with an empty mask and no flags on all supported targets.
+                let size = sigaction_layout.size.bytes_usize();
+                this.write_bytes_ptr(oldact.ptr(), iter::repeat(0u8).take(size))?;
+            }
+        }
+
+        if let Some(place) = new_action {
+            if let Some(old_action) = this.machine.signal_actions.insert(signum, place) {
+                this.deallocate_ptr(old_action.ptr(), None, MiriMemoryKind::Machine.into())?;
+            }
+        }
+
+        Ok(0)
+    }
+}
@@ -0,0 +1,9 @@
+//@only-target-linux
+
+fn main() {
+    let fd = unsafe { libc::memfd_create(b"miri\0".as_ptr().cast(), 0) };
+    assert!(fd >= 0);
+    unsafe {
+        libc::mmap(std::ptr::null_mut(), 4096, libc::PROT_READ, libc::MAP_SHARED, fd, 0); //~ ERROR: MAP_SHARED mappings of a memfd
+    }
+}
@@ -0,0 +1,16 @@
+//@ignore-target-windows: No libc on Windows
+
+fn main() {
+    unsafe {
+        let ptr = libc::mmap(
+            std::ptr::null_mut(),
+            page_size::get(),
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        );
+        libc::mprotect(ptr, page_size::get(), libc::PROT_NONE);
+        let _x = *(ptr as *mut u8); //~ ERROR: which is mapped without PROT_READ
+    }
+}
@@ -0,0 +1,10 @@
+//@error-in-other-file: `assume` called with `false`
+#![feature(hint_assert_unchecked)]
+
+fn main() {
+    let x = 5;
+    unsafe {
+        std::hint::assert_unchecked(x < 10);
+        std::hint::assert_unchecked(x > 42);
+    }
+}
@@ -0,0 +1,10 @@
+//@compile-flags: -Zmiri-report-leak-threshold=16
+//@error-in-other-file: memory leaked
+//@normalize-stderr-test: ".*│.*" -> "$$stripped$$"
+
+fn main() {
+    // This leak is below the threshold and not reported...
+    std::mem::forget(Box::new(42u32));
+    // ...but this one is.
+    std::mem::forget(Box::new([0u8; 16]));
+}
@@ -0,0 +1,137 @@
+//@only-target-linux
+
+use std::io::Error;
+use std::{ptr, slice};
+
+fn test_memfd_read_write() {
+    let fd = unsafe { libc::memfd_create(b"miri\0".as_ptr().cast(), libc::MFD_CLOEXEC) };
+    assert!(fd >= 0);
+
+    let data = b"hello, memfd";
+    let res = unsafe { libc::write(fd, data.as_ptr().cast(), data.len()) };
+    assert_eq!(res, data.len() as isize);
+
+    // Reading at the end of the file reads nothing
+    let mut buf = [0u8; 16];
+    let res = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+    assert_eq!(res, 0);
+
+    let res = unsafe { libc::lseek64(fd, 0, libc::SEEK_SET) };
+    assert_eq!(res, 0);
+    let res = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+    assert_eq!(res, data.len() as isize);
+    assert_eq!(&buf[..data.len()], data);
+
+    // Duplicated file descriptors share the file and its offset
+    let dup = unsafe { libc::fcntl(fd, libc::F_DUPFD, 0) };
+    let res = unsafe { libc::lseek64(dup, 7, libc::SEEK_SET) };
+    assert_eq!(res, 7);
+    let res = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+    assert_eq!(res, 5);
+    assert_eq!(&buf[..5], b"memfd");
+
+    // Truncating shrinks the file, extending it fills it with zeros
+    let res = unsafe { libc::ftruncate(fd, 5) };
+    assert_eq!(res, 0);
+    let res = unsafe { libc::ftruncate(fd, 8) };
+    assert_eq!(res, 0);
+    let res = unsafe { libc::lseek64(fd, 0, libc::SEEK_SET) };
+    assert_eq!(res, 0);
+    let res = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), buf.len()) };
+    assert_eq!(res, 8);
+    assert_eq!(&buf[..8], b"hello\0\0\0");
+
+    let res = unsafe { libc::ftruncate(fd, -1) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+
+    assert_eq!(unsafe { libc::close(dup) }, 0);
+    assert_eq!(unsafe { libc::close(fd) }, 0);
+}
+
+fn test_memfd_too_large() {
+    let fd = unsafe { libc::memfd_create(b"miri\0".as_ptr().cast(), 0) };
+    assert!(fd >= 0);
+
+    // Miri keeps the file in memory, so it refuses to grow it by an unreasonable amount
+    let res = unsafe { libc::ftruncate(fd, 1 << 40) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EFBIG);
+
+    let res = unsafe { libc::lseek64(fd, 1 << 40, libc::SEEK_SET) };
+    assert_eq!(res, 1 << 40);
+    let res = unsafe { libc::write(fd, b"x".as_ptr().cast(), 1) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EFBIG);
+
+    // The file is left as it was
+    let res = unsafe { libc::lseek64(fd, 0, libc::SEEK_END) };
+    assert_eq!(res, 0);
+
+    assert_eq!(unsafe { libc::close(fd) }, 0);
+}
+
+fn test_memfd_mmap() {
+    let page_size = page_size::get();
+    let fd = unsafe { libc::memfd_create(b"miri\0".as_ptr().cast(), 0) };
+    assert!(fd >= 0);
+
+    let res = unsafe { libc::ftruncate(fd, (page_size * 2) as libc::off_t) };
+    assert_eq!(res, 0);
+    let res = unsafe { libc::write(fd, b"first".as_ptr().cast(), 5) };
+    assert_eq!(res, 5);
+    let res = unsafe { libc::lseek64(fd, page_size as i64, libc::SEEK_SET) };
+    assert_eq!(res, page_size as i64);
+    let res = unsafe { libc::write(fd, b"second".as_ptr().cast(), 6) };
+    assert_eq!(res, 6);
+
+    // A private mapping sees the contents of the file, starting at the given offset
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            page_size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE,
+            fd,
+            page_size as libc::off_t,
+        )
+    };
+    assert_ne!(ptr, libc::MAP_FAILED);
+    let slice = unsafe { slice::from_raw_parts_mut(ptr as *mut u8, page_size) };
+    assert_eq!(&slice[..6], b"second");
+    assert!(slice[6..].iter().all(|b| *b == 0));
+
+    // Writes to a private mapping do not change the file
+    slice[0] = b'S';
+    let mut buf = [0u8; 6];
+    let res = unsafe { libc::lseek64(fd, page_size as i64, libc::SEEK_SET) };
+    assert_eq!(res, page_size as i64);
+    let res = unsafe { libc::read(fd, buf.as_mut_ptr().cast(), 6) };
+    assert_eq!(res, 6);
+    assert_eq!(&buf, b"second");
+
+    let res = unsafe { libc::munmap(ptr, page_size) };
+    assert_eq!(res, 0);
+
+    // The offset must be a multiple of the page size
+    let ptr = unsafe {
+        libc::mmap(ptr::null_mut(), page_size, libc::PROT_READ, libc::MAP_PRIVATE, fd, 1)
+    };
+    assert_eq!(ptr, libc::MAP_FAILED);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+
+    assert_eq!(unsafe { libc::close(fd) }, 0);
+
+    // Mapping a file descriptor that is not open fails
+    let ptr = unsafe {
+        libc::mmap(ptr::null_mut(), page_size, libc::PROT_READ, libc::MAP_PRIVATE, fd, 0)
+    };
+    assert_eq!(ptr, libc::MAP_FAILED);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EBADF);
+}
+
+fn main() {
+    test_memfd_read_write();
+    test_memfd_too_large();
+    test_memfd_mmap();
+}
@@ -178,6 +178,15 @@ fn test_clocks() {
     assert_eq!(is_error, 0);
     let is_error = unsafe { libc::clock_gettime(libc::CLOCK_MONOTONIC, tp.as_mut_ptr()) };
     assert_eq!(is_error, 0);
+    for clock in [libc::CLOCK_PROCESS_CPUTIME_ID, libc::CLOCK_THREAD_CPUTIME_ID] {
+        let is_error = unsafe { libc::clock_gettime(clock, tp.as_mut_ptr()) };
+        assert_eq!(is_error, 0);
+        let before = unsafe { tp.assume_init() };
+        let is_error = unsafe { libc::clock_gettime(clock, tp.as_mut_ptr()) };
+        assert_eq!(is_error, 0);
+        let after = unsafe { tp.assume_init() };
+        assert!((after.tv_sec, after.tv_nsec) >= (before.tv_sec, before.tv_nsec));
+    }
     #[cfg(any(target_os = "linux", target_os = "freebsd"))]
     {
         let is_error = unsafe { libc::clock_gettime(libc::CLOCK_REALTIME_COARSE, tp.as_mut_ptr()) };
@@ -357,6 +366,50 @@ fn test_reallocarray() {
     }
 }
 
+fn test_sigaction() {
+    use std::mem::MaybeUninit;
+    use std::ptr;
+
+    extern "C" fn handler(_signum: libc::c_int) {}
+
+    unsafe {
+        // Initially, every signal has its default action.
+        let mut old = MaybeUninit::<libc::sigaction>::uninit();
+        assert_eq!(libc::sigaction(libc::SIGUSR1, ptr::null(), old.as_mut_ptr()), 0);
+        assert_eq!(old.assume_init().sa_sigaction, libc::SIG_DFL);
+
+        // Install a handler and read it back.
+        let mut act: libc::sigaction = std::mem::zeroed();
+        act.sa_sigaction = handler as libc::sighandler_t;
+        act.sa_flags = libc::SA_RESTART;
+        assert_eq!(libc::sigaction(libc::SIGUSR1, &act, old.as_mut_ptr()), 0);
+        assert_eq!(old.assume_init().sa_sigaction, libc::SIG_DFL);
+        assert_eq!(libc::sigaction(libc::SIGUSR1, ptr::null(), old.as_mut_ptr()), 0);
+        let old = old.assume_init();
+        assert_eq!(old.sa_sigaction, handler as libc::sighandler_t);
+        assert_eq!(old.sa_flags, libc::SA_RESTART);
+
+        // Restore the previous action through an aliasing `act` and `oldact`.
+        let mut act: libc::sigaction = std::mem::zeroed();
+        act.sa_sigaction = libc::SIG_IGN;
+        let act_ptr = ptr::addr_of_mut!(act);
+        assert_eq!(libc::sigaction(libc::SIGUSR1, act_ptr, act_ptr), 0);
+        assert_eq!(act.sa_sigaction, handler as libc::sighandler_t);
+        assert_eq!(libc::sigaction(libc::SIGUSR1, ptr::null(), &mut act), 0);
+        assert_eq!(act.sa_sigaction, libc::SIG_IGN);
+
+        // Other signals are unaffected.
+        assert_eq!(libc::sigaction(libc::SIGUSR2, ptr::null(), &mut act), 0);
+        assert_eq!(act.sa_sigaction, libc::SIG_DFL);
+
+        // Invalid signal numbers and unchangeable signals are rejected.
+        assert_eq!(libc::sigaction(0, ptr::null(), &mut act), -1);
+        assert_eq!(std::io::Error::last_os_error().raw_os_error(), Some(libc::EINVAL));
+        assert_eq!(libc::sigaction(libc::SIGKILL, &act, ptr::null_mut()), -1);
+        assert_eq!(std::io::Error::last_os_error().raw_os_error(), Some(libc::EINVAL));
+    }
+}
+
 fn main() {
     test_posix_gettimeofday();
 
@@ -372,6 +425,8 @@ fn main() {
 
     test_dlsym();
 
+    test_sigaction();
+
     test_memcpy();
     test_strcpy();
 
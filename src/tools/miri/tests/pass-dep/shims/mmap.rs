@@ -33,6 +33,50 @@ fn test_mmap() {
     let res = unsafe { libc::munmap(ptr, page_size) };
     assert_eq!(res, 0i32);
 
+    // Anonymous shared mappings work just like private ones
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            page_size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_SHARED | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    assert_ne!(ptr, libc::MAP_FAILED);
+
+    let slice = unsafe { slice::from_raw_parts_mut(ptr as *mut u8, page_size) };
+    assert!(slice.iter().all(|b| *b == 0));
+
+    // Writes through one pointer are visible through another pointer to the same address
+    unsafe { *(ptr as *mut u8) = 42 };
+    let alias = ptr::from_exposed_addr::<u8>(ptr.expose_addr());
+    assert_eq!(unsafe { *alias }, 42);
+
+    let res = unsafe { libc::munmap(ptr, page_size) };
+    assert_eq!(res, 0i32);
+
+    // MAP_POPULATE is only a performance hint
+    #[cfg(target_os = "linux")]
+    {
+        let ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                page_size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS | libc::MAP_POPULATE,
+                -1,
+                0,
+            )
+        };
+        assert_ne!(ptr, libc::MAP_FAILED);
+        let slice = unsafe { slice::from_raw_parts_mut(ptr as *mut u8, page_size) };
+        assert!(slice.iter().all(|b| *b == 0));
+        let res = unsafe { libc::munmap(ptr, page_size) };
+        assert_eq!(res, 0i32);
+    }
+
     // Test all of our error conditions
     let ptr = unsafe {
         libc::mmap(
@@ -74,8 +118,8 @@ fn test_mmap() {
     assert_eq!(ptr, libc::MAP_FAILED);
     assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOTSUP);
 
-    // We don't support protections other than read+write
-    for prot in [libc::PROT_NONE, libc::PROT_EXEC, libc::PROT_READ, libc::PROT_WRITE] {
+    // We don't support executable mappings
+    for prot in [libc::PROT_EXEC, libc::PROT_READ | libc::PROT_WRITE | libc::PROT_EXEC] {
         let ptr = unsafe {
             libc::mmap(
                 ptr::null_mut(),
@@ -93,6 +137,290 @@ fn test_mmap() {
     let res = unsafe { libc::munmap(ptr::invalid_mut(1), page_size) };
     assert_eq!(res, -1);
     assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+
+    // We only support unmapping entire regions, starting at the address returned by mmap
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            page_size * 2,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    let res = unsafe { libc::munmap(ptr.byte_add(page_size), page_size) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+    let res = unsafe { libc::munmap(ptr, page_size * 2) };
+    assert_eq!(res, 0i32);
+}
+
+fn test_mprotect() {
+    let page_size = page_size::get();
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            page_size * 2,
+            libc::PROT_NONE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    assert_ne!(ptr, libc::MAP_FAILED);
+
+    // Make the second page accessible, and use it
+    let second_page = unsafe { ptr.byte_add(page_size) };
+    let res = unsafe { libc::mprotect(second_page, page_size, libc::PROT_READ | libc::PROT_WRITE) };
+    assert_eq!(res, 0i32);
+    let slice = unsafe { slice::from_raw_parts_mut(second_page as *mut u8, page_size) };
+    assert!(slice.iter().all(|b| *b == 0));
+    for b in slice.iter_mut() {
+        *b = 1;
+    }
+
+    // Make it read-only, the data is still there
+    let res = unsafe { libc::mprotect(second_page, page_size, libc::PROT_READ) };
+    assert_eq!(res, 0i32);
+    let slice = unsafe { slice::from_raw_parts(second_page as *const u8, page_size) };
+    assert!(slice.iter().all(|b| *b == 1));
+
+    // Test all of our error conditions
+    // Not aligned
+    let res = unsafe { libc::mprotect(ptr.byte_add(1), page_size, libc::PROT_READ) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+
+    // Zero size
+    let res = unsafe { libc::mprotect(ptr, 0, libc::PROT_READ) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+
+    // Executable memory is not supported
+    let res = unsafe { libc::mprotect(ptr, page_size, libc::PROT_EXEC) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOTSUP);
+
+    // Extends past the end of the mapping
+    let res = unsafe { libc::mprotect(ptr, page_size * 3, libc::PROT_READ) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOMEM);
+
+    // Not mapped by mmap
+    let res =
+        unsafe { libc::mprotect(ptr::invalid_mut(page_size * 64), page_size, libc::PROT_READ) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOMEM);
+
+    let res = unsafe { libc::munmap(ptr, page_size * 2) };
+    assert_eq!(res, 0i32);
+}
+
+fn test_madvise() {
+    let page_size = page_size::get();
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            page_size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    let slice = unsafe { slice::from_raw_parts_mut(ptr as *mut u8, page_size) };
+    for b in slice.iter_mut() {
+        *b = 1;
+    }
+
+    // Pure hints leave the memory untouched
+    for advice in [libc::MADV_NORMAL, libc::MADV_RANDOM, libc::MADV_SEQUENTIAL, libc::MADV_WILLNEED]
+    {
+        let res = unsafe { libc::madvise(ptr, page_size, advice) };
+        assert_eq!(res, 0i32);
+    }
+    for advice in [
+        libc::POSIX_MADV_NORMAL,
+        libc::POSIX_MADV_RANDOM,
+        libc::POSIX_MADV_SEQUENTIAL,
+        libc::POSIX_MADV_WILLNEED,
+        libc::POSIX_MADV_DONTNEED,
+    ] {
+        let res = unsafe { libc::posix_madvise(ptr, page_size, advice) };
+        assert_eq!(res, 0i32);
+    }
+    let slice = unsafe { slice::from_raw_parts_mut(ptr as *mut u8, page_size) };
+    assert!(slice.iter().all(|b| *b == 1));
+
+    // Allocators use these to give memory back; the contents afterwards are unspecified
+    for advice in [libc::MADV_FREE, libc::MADV_DONTNEED] {
+        let res = unsafe { libc::madvise(ptr, page_size, advice) };
+        assert_eq!(res, 0i32);
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // Discarding the pages of a private mapping of a memfd leaves the contents of the file
+        let fd = unsafe { libc::memfd_create(b"miri\0".as_ptr().cast(), 0) };
+        assert!(fd >= 0);
+        let res = unsafe { libc::write(fd, b"memfd".as_ptr().cast(), 5) };
+        assert_eq!(res, 5);
+        let file_ptr = unsafe {
+            libc::mmap(
+                ptr::null_mut(),
+                page_size,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE,
+                fd,
+                0,
+            )
+        };
+        assert_ne!(file_ptr, libc::MAP_FAILED);
+        let res = unsafe { libc::madvise(file_ptr, page_size, libc::MADV_DONTNEED) };
+        assert_eq!(res, 0i32);
+        let slice = unsafe { slice::from_raw_parts(file_ptr as *const u8, page_size) };
+        assert_eq!(&slice[..5], b"memfd");
+        assert!(slice[5..].iter().all(|b| *b == 0));
+        assert_eq!(unsafe { libc::munmap(file_ptr, page_size) }, 0);
+        assert_eq!(unsafe { libc::close(fd) }, 0);
+
+        // Miri does not support fork, so advice about fork is unsupported
+        let res = unsafe { libc::madvise(ptr, page_size, libc::MADV_DONTFORK) };
+        assert_eq!(res, -1);
+        assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOSYS);
+    }
+
+    // Test all of our error conditions
+    // Not aligned
+    let res = unsafe { libc::madvise(ptr.byte_add(1), page_size, libc::MADV_NORMAL) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+    let res = unsafe { libc::posix_madvise(ptr.byte_add(1), page_size, libc::POSIX_MADV_NORMAL) };
+    assert_eq!(res, libc::EINVAL);
+
+    // Not mapped by mmap
+    let res =
+        unsafe { libc::madvise(ptr::invalid_mut(page_size * 64), page_size, libc::MADV_NORMAL) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOMEM);
+
+    let res = unsafe { libc::munmap(ptr, page_size) };
+    assert_eq!(res, 0i32);
+}
+
+fn test_mincore() {
+    let page_size = page_size::get();
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            2 * page_size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    assert!(!ptr.is_null());
+
+    // Every page is reported as resident, and the length is rounded up to whole pages
+    let mut vec = [0u8; 3];
+    let res = unsafe { libc::mincore(ptr, page_size + 1, vec.as_mut_ptr().cast()) };
+    assert_eq!(res, 0i32);
+    assert_eq!(vec, [1, 1, 0]);
+
+    // Test all of our error conditions
+    // Not aligned
+    let res = unsafe { libc::mincore(ptr.byte_add(1), page_size, vec.as_mut_ptr().cast()) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+
+    // Zero length
+    let res = unsafe { libc::mincore(ptr, 0, vec.as_mut_ptr().cast()) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+
+    // Not mapped by mmap
+    let res = unsafe {
+        libc::mincore(ptr::invalid_mut(page_size * 64), page_size, vec.as_mut_ptr().cast())
+    };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOMEM);
+
+    // Output vector too small
+    let mut small = [0u8; 1];
+    let res = unsafe { libc::mincore(ptr, 2 * page_size, small.as_mut_ptr().cast()) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EFAULT);
+
+    // Output vector not writable
+    let res = unsafe { libc::mincore(ptr, page_size, ptr::null_mut()) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EFAULT);
+
+    // Output vector in a mapping without PROT_WRITE
+    for prot in [libc::PROT_READ, libc::PROT_NONE] {
+        let res = unsafe { libc::mprotect(ptr.byte_add(page_size), page_size, prot) };
+        assert_eq!(res, 0i32);
+        let res = unsafe { libc::mincore(ptr, page_size, ptr.byte_add(page_size).cast()) };
+        assert_eq!(res, -1);
+        assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EFAULT);
+    }
+
+    let res = unsafe { libc::munmap(ptr, 2 * page_size) };
+    assert_eq!(res, 0i32);
+}
+
+fn test_mlock() {
+    let page_size = page_size::get();
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            page_size,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    assert!(!ptr.is_null());
+
+    let res = unsafe { libc::mlock(ptr, page_size) };
+    assert_eq!(res, 0i32);
+    let res = unsafe { libc::munlock(ptr, page_size) };
+    assert_eq!(res, 0i32);
+
+    // Memory that was not mapped by mmap can be locked too
+    let mut secret = [0u8; 32];
+    let res = unsafe { libc::mlock(secret.as_mut_ptr().cast(), secret.len()) };
+    assert_eq!(res, 0i32);
+    let res = unsafe { libc::munlock(secret.as_mut_ptr().cast(), secret.len()) };
+    assert_eq!(res, 0i32);
+
+    // Test all of our error conditions
+    // Zero length
+    let res = unsafe { libc::mlock(ptr, 0) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::EINVAL);
+
+    // Out of bounds
+    let res = unsafe { libc::mlock(ptr, 2 * page_size) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOMEM);
+    let res = unsafe { libc::munlock(ptr::null(), page_size) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOMEM);
+
+    // Locking everything is not supported
+    let res = unsafe { libc::mlockall(libc::MCL_CURRENT) };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOSYS);
+    let res = unsafe { libc::munlockall() };
+    assert_eq!(res, -1);
+    assert_eq!(Error::last_os_error().raw_os_error().unwrap(), libc::ENOSYS);
+
+    let res = unsafe { libc::munmap(ptr, page_size) };
+    assert_eq!(res, 0i32);
 }
 
 #[cfg(target_os = "linux")]
@@ -120,7 +448,40 @@ fn test_mremap() {
     assert!(&slice[..page_size].iter().all(|b| *b == 1));
     assert!(&slice[page_size..].iter().all(|b| *b == 0));
 
-    let res = unsafe { libc::munmap(ptr, page_size * 2) };
+    // Keeping the size leaves the mapping where it is
+    let same = unsafe { libc::mremap(ptr, page_size * 2, page_size * 2, libc::MREMAP_MAYMOVE) };
+    assert_eq!(same, ptr);
+
+    // Shrinking keeps the data that is still in bounds
+    let ptr = unsafe { libc::mremap(ptr, page_size * 2, page_size, libc::MREMAP_MAYMOVE) };
+    assert_ne!(ptr, libc::MAP_FAILED);
+    let slice = unsafe { slice::from_raw_parts_mut(ptr as *mut u8, page_size) };
+    assert!(slice.iter().all(|b| *b == 1));
+
+    let res = unsafe { libc::munmap(ptr, page_size) };
+    assert_eq!(res, 0i32);
+
+    // Sizes are rounded up to whole pages, both for the no-op and for growing.
+    let ptr = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            100,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    assert_ne!(ptr, libc::MAP_FAILED);
+    unsafe { *(ptr as *mut u8).add(page_size - 1) = 1 };
+    let same = unsafe { libc::mremap(ptr, 100, 200, libc::MREMAP_MAYMOVE) };
+    assert_eq!(same, ptr);
+    let ptr = unsafe { libc::mremap(ptr, 200, page_size + 1, libc::MREMAP_MAYMOVE) };
+    assert_ne!(ptr, libc::MAP_FAILED);
+    let slice = unsafe { slice::from_raw_parts(ptr as *const u8, page_size * 2) };
+    assert_eq!(slice[page_size - 1], 1);
+    assert!(slice[page_size..].iter().all(|b| *b == 0));
+    let res = unsafe { libc::munmap(ptr, page_size + 1) };
     assert_eq!(res, 0i32);
 
     // Test all of our error conditions
@@ -143,6 +504,10 @@ fn test_mremap() {
 
 fn main() {
     test_mmap();
+    test_mprotect();
+    test_madvise();
+    test_mincore();
+    test_mlock();
     #[cfg(target_os = "linux")]
     test_mremap();
 }
@@ -0,0 +1,23 @@
+//@ignore-target-windows: No libc on Windows
+
+use std::ffi::CStr;
+use std::ptr;
+
+fn main() {
+    let program = CStr::from_bytes_with_nul(b"true\0").unwrap();
+    let argv = [program.as_ptr() as *mut libc::c_char, ptr::null_mut()];
+    let envp = [ptr::null_mut()];
+    let mut pid = 0;
+
+    let res = unsafe {
+        libc::posix_spawnp(
+            &mut pid,
+            program.as_ptr(),
+            ptr::null(),
+            ptr::null(),
+            argv.as_ptr(),
+            envp.as_ptr(),
+        )
+    };
+    assert_eq!(res, libc::ENOSYS);
+}
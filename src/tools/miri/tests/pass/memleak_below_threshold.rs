@@ -0,0 +1,7 @@
+//@compile-flags: -Zmiri-report-leak-threshold=16
+
+fn main() {
+    // Small leaks are not reported.
+    std::mem::forget(Box::new(42u32));
+    std::mem::forget(vec![0u8; 15]);
+}
@@ -0,0 +1,29 @@
+#![feature(derive_smart_pointer)]
+
+use std::marker::SmartPointer;
+
+#[derive(SmartPointer)]
+//~^ ERROR: `SmartPointer` can only be derived on `struct`s
+enum NotStruct<'a, #[pointee] T: ?Sized> {
+    Ptr(&'a T),
+}
+
+#[derive(SmartPointer)]
+//~^ ERROR: `SmartPointer` can only be derived if one of the type parameters is marked as `#[pointee]`
+struct NoPointee<'a, T: ?Sized> {
+    ptr: &'a T,
+}
+
+#[derive(SmartPointer)]
+//~^ ERROR: `SmartPointer` can only be derived with a single `#[pointee]` type parameter
+struct TooManyPointees<
+    'a,
+    #[pointee] A: ?Sized,
+    #[pointee] B: ?Sized,
+>(&'a A, &'a B);
+
+#[derive(SmartPointer)]
+struct PointeeLifetime<#[pointee] 'a, T: ?Sized>(&'a T);
+//~^ ERROR: `#[pointee]` can only be used on type parameters
+
+fn main() {}
@@ -0,0 +1,97 @@
+// run-pass
+#![feature(derive_smart_pointer, arbitrary_self_types)]
+
+use std::marker::SmartPointer;
+
+#[derive(SmartPointer)]
+struct MyPointer<'a, #[pointee] T: ?Sized> {
+    ptr: &'a T,
+}
+
+impl<T: ?Sized> Copy for MyPointer<'_, T> {}
+impl<T: ?Sized> Clone for MyPointer<'_, T> {
+    fn clone(&self) -> Self {
+        Self { ptr: self.ptr }
+    }
+}
+
+impl<'a, T: ?Sized> core::ops::Deref for MyPointer<'a, T> {
+    type Target = T;
+    fn deref(&self) -> &T {
+        self.ptr
+    }
+}
+
+struct MyValue(u32);
+
+impl MyValue {
+    fn through_pointer(self: MyPointer<'_, Self>) -> u32 {
+        self.ptr.0
+    }
+}
+
+trait MyTrait {
+    fn through_trait(&self) -> u32;
+    fn through_trait_and_pointer(self: MyPointer<'_, Self>) -> u32;
+}
+
+impl MyTrait for MyValue {
+    fn through_trait(&self) -> u32 {
+        self.0
+    }
+
+    fn through_trait_and_pointer(self: MyPointer<'_, Self>) -> u32 {
+        self.ptr.0
+    }
+}
+
+// The pointee does not have to be the last or only generic parameter.
+#[derive(SmartPointer)]
+struct Tagged<'a, const N: usize, #[pointee] T: ?Sized, Tag> {
+    ptr: &'a T,
+    tag: std::marker::PhantomData<[Tag; N]>,
+}
+
+// A smart pointer that is used in the type it points to.
+#[derive(SmartPointer)]
+struct Link<#[pointee] T: ?Sized>(Box<T>);
+
+struct Node {
+    value: u32,
+    next: Option<Link<Node>>,
+}
+
+trait Sum {
+    fn sum(&self) -> u32;
+}
+
+impl Sum for Node {
+    fn sum(&self) -> u32 {
+        self.value + self.next.as_ref().map_or(0, |next| next.0.sum())
+    }
+}
+
+pub fn main() {
+    let v = MyValue(10);
+    let ptr = MyPointer { ptr: &v };
+    assert_eq!(10, ptr.through_pointer());
+    assert_eq!(10, ptr.through_trait());
+
+    // Trait object coercions, including dispatch through the smart pointer itself.
+    let dptr = ptr as MyPointer<dyn MyTrait>;
+    assert_eq!(10, dptr.through_trait());
+    assert_eq!(10, dptr.through_trait_and_pointer());
+
+    // Coercions to slices.
+    let array = [1, 2, 3];
+    let slice: MyPointer<[i32]> = MyPointer { ptr: &array };
+    assert_eq!(slice.len(), 3);
+    let tagged: Tagged<'_, 2, [i32], ()> =
+        Tagged { ptr: &array, tag: std::marker::PhantomData };
+    assert_eq!(tagged.ptr.len(), 3);
+
+    // Coercions of recursive types.
+    let list = Node { value: 1, next: Some(Link(Box::new(Node { value: 2, next: None }))) };
+    let link: Link<dyn Sum> = Link(Box::new(list));
+    assert_eq!(3, link.0.sum());
+}
@@ -0,0 +1,18 @@
+#![feature(diagnostic_namespace)]
+
+#[diagnostic::on_unimplemented(
+    message = "Foo",
+    label = "Bar",
+    note = "`{Self}` does not implement `Foo<{T}>`",
+    note = "unknown parameter {U}"
+    //~^WARN there is no parameter `U` on trait `Foo`
+    //~|WARN there is no parameter `U` on trait `Foo`
+)]
+trait Foo<T> {}
+
+fn takes_foo(_: impl Foo<i32>) {}
+
+fn main() {
+    takes_foo(());
+    //~^ERROR Foo
+}
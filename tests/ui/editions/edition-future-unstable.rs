@@ -0,0 +1,3 @@
+// compile-flags: --edition future
+
+fn main() {}
@@ -0,0 +1,15 @@
+// check-pass
+// compile-flags: --edition future -Zunstable-options
+
+// `--edition future` selects the edition that is still in development, which is the only one
+// with `gen` blocks.
+
+#![feature(gen_blocks)]
+
+fn main() {
+    let numbers = gen {
+        yield 1i32;
+        yield 2;
+    };
+    for _ in numbers {}
+}
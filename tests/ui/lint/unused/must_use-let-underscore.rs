@@ -0,0 +1,85 @@
+// check-pass
+#![warn(let_underscore_must_use)]
+
+#[must_use]
+fn must_use() -> u32 {
+    0
+}
+
+#[must_use = "the checksum should be compared against the expected one"]
+fn checksum() -> u32 {
+    0
+}
+
+#[must_use]
+struct MustUse;
+
+#[must_use = "the token should be redeemed"]
+struct Token;
+
+#[must_use]
+trait Critical {}
+
+impl Critical for MustUse {}
+
+struct S;
+
+impl S {
+    #[must_use]
+    fn method(&self) -> u32 {
+        0
+    }
+}
+
+#[must_use]
+fn must_use_returns_must_use() -> MustUse {
+    MustUse
+}
+
+fn make() -> MustUse {
+    MustUse
+}
+
+fn boxed() -> Box<MustUse> {
+    Box::new(MustUse)
+}
+
+fn pair() -> (u32, Token) {
+    (0, Token)
+}
+
+fn critical() -> impl Critical {
+    MustUse
+}
+
+fn fallible() -> Result<(), ()> {
+    Ok(())
+}
+
+fn not_must_use() -> u32 {
+    0
+}
+
+fn main() {
+    let _ = must_use(); //~WARNING non-binding let on the return value of `must_use`
+    let _ = checksum(); //~WARNING non-binding let on the return value of `checksum`
+    let _ = S.method(); //~WARNING non-binding let on the return value of `S::method`
+    let _ = must_use_returns_must_use();
+    //~^ WARNING non-binding let on the return value of `must_use_returns_must_use`
+
+    let _ = make(); //~WARNING non-binding let on a value of type `MustUse`
+    let _ = Token; //~WARNING non-binding let on a value of type `Token`
+    let _ = boxed(); //~WARNING non-binding let on a value of type `Box<MustUse>`
+    let _ = pair(); //~WARNING non-binding let on a value of type `(u32, Token)`
+    let _ = critical(); //~WARNING non-binding let on a value of type `impl Critical`
+    let _ = fallible(); //~WARNING non-binding let on a value of type `Result<(), ()>`
+
+    let _ = not_must_use();
+    let _ = ();
+    let _unused = must_use();
+    let _unused = make();
+    #[allow(let_underscore_must_use)]
+    let _ = must_use();
+    #[allow(let_underscore_must_use)]
+    let _ = make();
+}
@@ -0,0 +1,31 @@
+// force-host
+// no-prefer-dynamic
+
+#![crate_type = "proc-macro"]
+#![feature(proc_macro_diagnostic)]
+
+extern crate proc_macro;
+
+use proc_macro::{Applicability, TokenStream, TokenTree};
+
+#[proc_macro]
+pub fn greet(input: TokenStream) -> TokenStream {
+    for tree in input {
+        if let TokenTree::Ident(ref ident) = tree {
+            if ident.to_string() == "hello" {
+                ident
+                    .span()
+                    .error("greet with `hi`")
+                    .span_suggestion(
+                        ident.span(),
+                        "use `hi` instead",
+                        "hi",
+                        Applicability::MachineApplicable,
+                    )
+                    .emit();
+            }
+        }
+    }
+
+    TokenStream::new()
+}
@@ -0,0 +1,11 @@
+// aux-build:suggestion.rs
+// run-rustfix
+
+extern crate suggestion;
+
+use suggestion::greet;
+
+fn main() {
+    greet!(hi);
+    greet!(hello); //~ ERROR greet with `hi`
+}
@@ -0,0 +1,29 @@
+// check-pass
+// run-rustfix
+
+#![allow(dead_code)]
+
+use std::ops::Deref;
+
+trait Bar<T> {}
+trait Foo<T>: Bar<T> {}
+
+impl<T> Deref for dyn Foo<T>
+//~^ WARN this `Deref` implementation is covered by an implicit supertrait coercion
+where
+    T: Clone,
+{
+    type Target = dyn Bar<T>;
+
+    fn deref(&self) -> &Self::Target {
+        self
+    }
+}
+
+fn take_bar<T: Clone>(_: &dyn Bar<T>) {}
+
+fn take_foo<T: Clone>(foo: &dyn Foo<T>) {
+    take_bar(foo)
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+// check-pass
+// run-rustfix
+
+#![allow(dead_code)]
+
+use std::ops::Deref;
+
+trait Bar<'a> {}
+trait Foo<'a>: Bar<'a> {}
+
+impl<'a> Deref for dyn Foo<'a> + 'a {
+    //~^ WARN this `Deref` implementation is covered by an implicit supertrait coercion
+    type Target = dyn Bar<'a> + 'a;
+
+    fn deref(&self) -> &Self::Target {
+        self
+    }
+}
+
+fn take_bar<'a>(_: &(dyn Bar<'a> + 'a)) {}
+
+fn take_foo<'a>(foo: &(dyn Foo<'a> + 'a)) {
+    take_bar(foo)
+}
+
+fn main() {}
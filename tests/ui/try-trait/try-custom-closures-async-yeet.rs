@@ -0,0 +1,99 @@
+// run-pass
+// edition:2021
+
+// Checks that `?` on a custom `Try` type infers its residual correctly inside closures and async
+// blocks, and that `do yeet` goes through the type's own `FromResidual` impl.
+
+#![feature(noop_waker)]
+#![feature(try_trait_v2)]
+#![feature(try_trait_v2_yeet)]
+#![feature(yeet_expr)]
+
+use std::future::Future;
+use std::ops::{ControlFlow, FromResidual, Try, Yeet};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug, PartialEq)]
+enum Outcome<T> {
+    Done(T),
+    Failed(&'static str),
+}
+
+struct Failure(&'static str);
+
+impl<T> Try for Outcome<T> {
+    type Output = T;
+    type Residual = Failure;
+
+    fn from_output(output: T) -> Self {
+        Outcome::Done(output)
+    }
+
+    fn branch(self) -> ControlFlow<Failure, T> {
+        match self {
+            Outcome::Done(t) => ControlFlow::Continue(t),
+            Outcome::Failed(msg) => ControlFlow::Break(Failure(msg)),
+        }
+    }
+}
+
+impl<T> FromResidual<Failure> for Outcome<T> {
+    fn from_residual(Failure(msg): Failure) -> Self {
+        Outcome::Failed(msg)
+    }
+}
+
+impl<T> FromResidual<Yeet<&'static str>> for Outcome<T> {
+    fn from_residual(Yeet(msg): Yeet<&'static str>) -> Self {
+        Outcome::Failed(msg)
+    }
+}
+
+fn parse(s: &str) -> Outcome<u32> {
+    match s.parse() {
+        Ok(n) => Outcome::Done(n),
+        Err(_) => Outcome::Failed("not a number"),
+    }
+}
+
+fn checked(n: u32) -> Outcome<u32> {
+    if n > 100 {
+        do yeet "too large";
+    }
+    Outcome::Done(n)
+}
+
+fn block_on<F: Future>(fut: F) -> F::Output {
+    let mut fut = pin!(fut);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(out) = fut.as_mut().poll(&mut cx) {
+            return out;
+        }
+    }
+}
+
+async fn sum_async(a: &str, b: &str) -> Outcome<u32> {
+    let a = async { parse(a) }.await?;
+    let b = parse(b)?;
+    checked(a + b)
+}
+
+fn main() {
+    // The closure's return type is only known from its body, so `?` has to pick the residual
+    // of `Outcome` rather than falling back to `Result` or `Option`.
+    let double = |s: &str| {
+        let n = parse(s)?;
+        Outcome::Done(n * 2)
+    };
+    assert_eq!(double("21"), Outcome::Done(42));
+    assert_eq!(double("x"), Outcome::Failed("not a number"));
+
+    assert_eq!(checked(7), Outcome::Done(7));
+    assert_eq!(checked(101), Outcome::Failed("too large"));
+
+    assert_eq!(block_on(sum_async("1", "2")), Outcome::Done(3));
+    assert_eq!(block_on(sum_async("1", "y")), Outcome::Failed("not a number"));
+    assert_eq!(block_on(sum_async("100", "1")), Outcome::Failed("too large"));
+}